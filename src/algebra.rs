@@ -0,0 +1,121 @@
+//! This module provides algebraic reshaping of nested [EitherOf], [BothOf] and [AnyOf] types.
+//!
+//! - Associativity: `assoc_left` and `assoc_right` for [EitherOf] and [AnyOf],
+//! - Distributivity: [BothOf::distribute] and its inverse [EitherOf::factor],
+//! - Balancing: [AnyOf::into_balanced] and [AnyOf::into_right_leaning] convert between
+//!   a right-leaning `AnyOf<A, AnyOf<B, AnyOf<C, D>>>` and the balanced [AnyOf4].
+//!
+//! The `AnyOf` conversions treat a nested `Neither` as an absent side: for instance,
+//! `Left(Neither)` is converted to `Neither`. All the other values round-trip.
+//!
+//! # Examples
+//!
+//! ```rust
+//! use any_of::{AnyOf, BothOf, EitherOf, Left, Right};
+//!
+//! let nested: AnyOf<AnyOf<i32, char>, &str> = AnyOf::new_both(AnyOf::new_right('b'), "c");
+//! let reassociated = nested.assoc_right();
+//! assert_eq!(reassociated, AnyOf::new_right(AnyOf::new_both('b', "c")));
+//! assert_eq!(reassociated.assoc_left(), nested);
+//!
+//! let both: BothOf<EitherOf<i32, char>, &str> = BothOf::new(Right('b'), "c");
+//! assert_eq!(both.distribute(), Right(BothOf::new('b', "c")));
+//! assert_eq!(both.distribute().factor(), both);
+//! ```
+
+use crate::{AnyOf, AnyOf4, BothOf, EitherOf, Left, Right};
+
+/// Converts a nested `Neither` into an absent side.
+fn non_neither<L, R>(any: AnyOf<L, R>) -> Option<AnyOf<L, R>> {
+    (!any.is_neither()).then_some(any)
+}
+
+impl<A, B, C> EitherOf<EitherOf<A, B>, C> {
+    /// Re-associates `(A | B) | C` into `A | (B | C)`.
+    pub fn assoc_right(self) -> EitherOf<A, EitherOf<B, C>> {
+        match self {
+            Left(Left(a)) => Left(a),
+            Left(Right(b)) => Right(Left(b)),
+            Right(c) => Right(Right(c)),
+        }
+    }
+}
+
+impl<A, B, C> EitherOf<A, EitherOf<B, C>> {
+    /// Re-associates `A | (B | C)` into `(A | B) | C`.
+    pub fn assoc_left(self) -> EitherOf<EitherOf<A, B>, C> {
+        match self {
+            Left(a) => Left(Left(a)),
+            Right(Left(b)) => Left(Right(b)),
+            Right(Right(c)) => Right(c),
+        }
+    }
+}
+
+impl<A, B, C> AnyOf<AnyOf<A, B>, C> {
+    /// Re-associates `AnyOf<AnyOf<A, B>, C>` into `AnyOf<A, AnyOf<B, C>>`.
+    pub fn assoc_right(self) -> AnyOf<A, AnyOf<B, C>> {
+        let (ab, c) = self.into_opt2();
+        let (a, b) = ab.map_or((None, None), AnyOf::into_opt2);
+        AnyOf::new(a, non_neither(AnyOf::new(b, c)))
+    }
+}
+
+impl<A, B, C> AnyOf<A, AnyOf<B, C>> {
+    /// Re-associates `AnyOf<A, AnyOf<B, C>>` into `AnyOf<AnyOf<A, B>, C>`.
+    pub fn assoc_left(self) -> AnyOf<AnyOf<A, B>, C> {
+        let (a, bc) = self.into_opt2();
+        let (b, c) = bc.map_or((None, None), AnyOf::into_opt2);
+        AnyOf::new(non_neither(AnyOf::new(a, b)), c)
+    }
+}
+
+impl<A, B, C> BothOf<EitherOf<A, B>, C> {
+    /// Distributes `(A | B) & C` into `(A & C) | (B & C)`.
+    pub fn distribute(self) -> EitherOf<BothOf<A, C>, BothOf<B, C>> {
+        match self.left {
+            Left(a) => Left(BothOf::new(a, self.right)),
+            Right(b) => Right(BothOf::new(b, self.right)),
+        }
+    }
+}
+
+impl<A, B, C> EitherOf<BothOf<A, C>, BothOf<B, C>> {
+    /// Factors `(A & C) | (B & C)` into `(A | B) & C`: the inverse of [BothOf::distribute].
+    pub fn factor(self) -> BothOf<EitherOf<A, B>, C> {
+        match self {
+            Left(BothOf { left, right }) => BothOf::new(Left(left), right),
+            Right(BothOf { left, right }) => BothOf::new(Right(left), right),
+        }
+    }
+}
+
+impl<A, B, C, D> AnyOf<A, AnyOf<B, AnyOf<C, D>>> {
+    /// Converts a right-leaning `AnyOf<A, AnyOf<B, AnyOf<C, D>>>` into the balanced [AnyOf4].
+    ///
+    /// # Examples
+    /// ```rust
+    /// use any_of::{AnyOf, AnyOf4};
+    ///
+    /// let leaning: AnyOf<i32, AnyOf<i32, AnyOf<i32, i32>>> =
+    ///     AnyOf::new_both(1, AnyOf::new_right(AnyOf::new_left(3)));
+    /// assert_eq!(leaning.into_balanced(), AnyOf4::new4(Some(1), None, Some(3), None));
+    /// ```
+    pub fn into_balanced(self) -> AnyOf4<A, B, C, D> {
+        let (a, bcd) = self.into_opt2();
+        let (b, cd) = bcd.map_or((None, None), AnyOf::into_opt2);
+        let (c, d) = cd.map_or((None, None), AnyOf::into_opt2);
+        AnyOf4::new4(a, b, c, d)
+    }
+}
+
+impl<A, B, C, D> AnyOf4<A, B, C, D> {
+    /// Converts into a right-leaning `AnyOf<A, AnyOf<B, AnyOf<C, D>>>`.
+    ///
+    /// This is the inverse of [AnyOf::into_balanced].
+    pub fn into_right_leaning(self) -> AnyOf<A, AnyOf<B, AnyOf<C, D>>> {
+        let (a, b, c, d) = self.into_opt4();
+        let cd = non_neither(AnyOf::new(c, d));
+        AnyOf::new(a, non_neither(AnyOf::new(b, cd)))
+    }
+}
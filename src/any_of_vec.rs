@@ -0,0 +1,361 @@
+//! This module provides `AnyOfVec`, a struct-of-arrays collection of [AnyOf] values (`alloc` feature).
+//!
+//! A `Vec<AnyOf<L, R>>` pays the size of the enum for each element, and scatters the left
+//! and right values in memory.
+//! An `AnyOfVec<L, R>` stores:
+//!
+//! - a presence bitmap for each side,
+//! - the left values in a dense `Vec<L>`, and the right values in a dense `Vec<R>`.
+//!
+//! Each element is accessed in O(1) as an `AnyOf<&L, &R>`, and its shape is a tag with the
+//! same bits as [crate::PackedAnyOf::tag]: `0b01` for a left value, `0b10` for a right value.
+//! Filtering by shape reads the bitmaps only, not the values.
+//!
+//! # Examples
+//!
+//! ```rust
+//! use any_of::any_of_vec::AnyOfVec;
+//! use any_of::AnyOf;
+//!
+//! let mut values: AnyOfVec<i32, &str> = AnyOfVec::new();
+//! values.push(AnyOf::new_left(1));
+//! values.push(AnyOf::new_both(2, "two"));
+//! values.push(AnyOf::new_right("three"));
+//!
+//! assert_eq!(values.get(1), Some(AnyOf::new_both(&2, &"two")));
+//! assert_eq!(values.lefts(), &[1, 2]);
+//! assert_eq!(values.rights(), &["two", "three"]);
+//! assert_eq!(values.positions(0b11).collect::<Vec<_>>(), vec![1]);
+//! assert_eq!(values.pop(), Some(AnyOf::new_right("three")));
+//! ```
+
+use crate::AnyOf;
+use crate::packed::{LEFT, RIGHT};
+use alloc::vec::Vec;
+use core::fmt::{Debug, Formatter};
+use core::iter::FusedIterator;
+
+const WORD_BITS: usize = u64::BITS as usize;
+
+/// A presence bitmap and the dense values of one side.
+///
+/// `ranks[w]` is the number of values stored before the word `w` of `bits`.
+#[derive(Clone, Eq, PartialEq, Hash)]
+struct Column<T> {
+    bits: Vec<u64>,
+    ranks: Vec<usize>,
+    values: Vec<T>,
+}
+
+impl<T> Column<T> {
+    fn new() -> Self {
+        Self {
+            bits: Vec::new(),
+            ranks: Vec::new(),
+            values: Vec::new(),
+        }
+    }
+
+    fn is_set(&self, index: usize) -> bool {
+        self.bits[index / WORD_BITS] & (1 << (index % WORD_BITS)) != 0
+    }
+
+    /// Returns the position in `values` of the element at `index`, if its bit is set.
+    fn rank(&self, index: usize) -> Option<usize> {
+        let word = index / WORD_BITS;
+        let bit = 1 << (index % WORD_BITS);
+        let bits = self.bits[word];
+        (bits & bit != 0).then(|| self.ranks[word] + (bits & (bit - 1)).count_ones() as usize)
+    }
+
+    fn get(&self, index: usize) -> Option<&T> {
+        self.rank(index).map(|rank| &self.values[rank])
+    }
+
+    /// Appends the element at `index`, which must be the length of the collection.
+    fn push(&mut self, index: usize, value: Option<T>) {
+        if index / WORD_BITS == self.bits.len() {
+            self.bits.push(0);
+            self.ranks.push(self.values.len());
+        }
+        if let Some(value) = value {
+            self.bits[index / WORD_BITS] |= 1 << (index % WORD_BITS);
+            self.values.push(value);
+        }
+    }
+
+    /// Removes the element at `index`, which must be the last one of the collection.
+    fn pop(&mut self, index: usize) -> Option<T> {
+        let value = if self.is_set(index) {
+            self.bits[index / WORD_BITS] &= !(1 << (index % WORD_BITS));
+            self.values.pop()
+        } else {
+            None
+        };
+        self.bits.truncate(index.div_ceil(WORD_BITS));
+        self.ranks.truncate(index.div_ceil(WORD_BITS));
+        value
+    }
+
+    fn clear(&mut self) {
+        self.bits.clear();
+        self.ranks.clear();
+        self.values.clear();
+    }
+}
+
+/// A struct-of-arrays collection of [AnyOf] values.
+///
+/// See the [module documentation](self).
+#[derive(Clone, Eq, PartialEq, Hash)]
+pub struct AnyOfVec<L, R = L> {
+    len: usize,
+    left: Column<L>,
+    right: Column<R>,
+}
+
+impl<L, R> Default for AnyOfVec<L, R> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<L, R> AnyOfVec<L, R> {
+    /// Creates an empty collection.
+    pub fn new() -> Self {
+        Self {
+            len: 0,
+            left: Column::new(),
+            right: Column::new(),
+        }
+    }
+
+    /// Returns the number of elements, including the `Neither` ones.
+    pub fn len(&self) -> usize {
+        self.len
+    }
+
+    /// True if the collection has no element.
+    pub fn is_empty(&self) -> bool {
+        self.len == 0
+    }
+
+    /// Removes all the elements.
+    pub fn clear(&mut self) {
+        self.len = 0;
+        self.left.clear();
+        self.right.clear();
+    }
+
+    /// Appends an element.
+    pub fn push(&mut self, value: AnyOf<L, R>) {
+        let (left, right) = value.into_opt2();
+        self.left.push(self.len, left);
+        self.right.push(self.len, right);
+        self.len += 1;
+    }
+
+    /// Removes the last element and returns it, or `None` if the collection is empty.
+    pub fn pop(&mut self) -> Option<AnyOf<L, R>> {
+        let index = self.len.checked_sub(1)?;
+        self.len = index;
+        let left = self.left.pop(index);
+        let right = self.right.pop(index);
+        Some(AnyOf::new(left, right))
+    }
+
+    /// Returns the element at `index`, or `None` if out of bounds.
+    pub fn get(&self, index: usize) -> Option<AnyOf<&L, &R>> {
+        (index < self.len).then(|| AnyOf::new(self.left.get(index), self.right.get(index)))
+    }
+
+    /// Returns the shape of the element at `index`, or `None` if out of bounds.
+    ///
+    /// The shape is `0b00` for `Neither`, `0b01` for `Left`, `0b10` for `Right` and `0b11` for `Both`.
+    pub fn shape(&self, index: usize) -> Option<u8> {
+        (index < self.len).then(|| self.shape_unchecked(index))
+    }
+
+    fn shape_unchecked(&self, index: usize) -> u8 {
+        let left = if self.left.is_set(index) { LEFT } else { 0 };
+        let right = if self.right.is_set(index) { RIGHT } else { 0 };
+        left | right
+    }
+
+    /// Returns the dense column of the left values, in order.
+    pub fn lefts(&self) -> &[L] {
+        &self.left.values
+    }
+
+    /// Returns the dense column of the right values, in order.
+    pub fn rights(&self) -> &[R] {
+        &self.right.values
+    }
+
+    /// Returns an iterator over the elements.
+    pub fn iter(&self) -> Iter<'_, L, R> {
+        Iter {
+            vec: self,
+            index: 0,
+            left: 0,
+            right: 0,
+        }
+    }
+
+    /// Returns the indices of the elements with the given `shape`, without reading the values.
+    ///
+    /// See [AnyOfVec::shape].
+    pub fn positions(&self, shape: u8) -> impl Iterator<Item = usize> + '_ {
+        (0..self.len).filter(move |&index| self.shape_unchecked(index) == shape)
+    }
+
+    /// Returns an iterator over the elements with the given `shape`.
+    ///
+    /// The values of the other elements are not read. See [AnyOfVec::shape].
+    pub fn filter_shape(&self, shape: u8) -> impl Iterator<Item = AnyOf<&L, &R>> + '_ {
+        self.positions(shape)
+            .map(|index| AnyOf::new(self.left.get(index), self.right.get(index)))
+    }
+
+    /// Converts into a `Vec` of [AnyOf].
+    pub fn into_vec(self) -> Vec<AnyOf<L, R>> {
+        self.into_iter().collect()
+    }
+}
+
+impl<L: Debug, R: Debug> Debug for AnyOfVec<L, R> {
+    /// Formats as a list of [AnyOf].
+    fn fmt(&self, f: &mut Formatter<'_>) -> core::fmt::Result {
+        f.debug_list().entries(self.iter()).finish()
+    }
+}
+
+/// An iterator over the elements of an [AnyOfVec], created by [AnyOfVec::iter].
+pub struct Iter<'a, L, R> {
+    vec: &'a AnyOfVec<L, R>,
+    index: usize,
+    left: usize,
+    right: usize,
+}
+
+impl<'a, L, R> Iterator for Iter<'a, L, R> {
+    type Item = AnyOf<&'a L, &'a R>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        if self.index >= self.vec.len {
+            return None;
+        }
+        let left = self.vec.left.is_set(self.index).then(|| {
+            self.left += 1;
+            &self.vec.left.values[self.left - 1]
+        });
+        let right = self.vec.right.is_set(self.index).then(|| {
+            self.right += 1;
+            &self.vec.right.values[self.right - 1]
+        });
+        self.index += 1;
+        Some(AnyOf::new(left, right))
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        let len = self.vec.len - self.index;
+        (len, Some(len))
+    }
+}
+
+impl<L, R> ExactSizeIterator for Iter<'_, L, R> {}
+
+impl<L, R> FusedIterator for Iter<'_, L, R> {}
+
+/// An owning iterator over the elements of an [AnyOfVec], created by `into_iter`.
+pub struct IntoIter<L, R> {
+    len: usize,
+    index: usize,
+    left_bits: Vec<u64>,
+    right_bits: Vec<u64>,
+    left: alloc::vec::IntoIter<L>,
+    right: alloc::vec::IntoIter<R>,
+}
+
+impl<L, R> Iterator for IntoIter<L, R> {
+    type Item = AnyOf<L, R>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        if self.index >= self.len {
+            return None;
+        }
+        let word = self.index / WORD_BITS;
+        let bit = 1 << (self.index % WORD_BITS);
+        let left = (self.left_bits[word] & bit != 0)
+            .then(|| self.left.next())
+            .flatten();
+        let right = (self.right_bits[word] & bit != 0)
+            .then(|| self.right.next())
+            .flatten();
+        self.index += 1;
+        Some(AnyOf::new(left, right))
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        let len = self.len - self.index;
+        (len, Some(len))
+    }
+}
+
+impl<L, R> ExactSizeIterator for IntoIter<L, R> {}
+
+impl<L, R> FusedIterator for IntoIter<L, R> {}
+
+impl<L, R> IntoIterator for AnyOfVec<L, R> {
+    type Item = AnyOf<L, R>;
+    type IntoIter = IntoIter<L, R>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        IntoIter {
+            len: self.len,
+            index: 0,
+            left_bits: self.left.bits,
+            right_bits: self.right.bits,
+            left: self.left.values.into_iter(),
+            right: self.right.values.into_iter(),
+        }
+    }
+}
+
+impl<'a, L, R> IntoIterator for &'a AnyOfVec<L, R> {
+    type Item = AnyOf<&'a L, &'a R>;
+    type IntoIter = Iter<'a, L, R>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        self.iter()
+    }
+}
+
+impl<L, R> Extend<AnyOf<L, R>> for AnyOfVec<L, R> {
+    fn extend<I: IntoIterator<Item = AnyOf<L, R>>>(&mut self, iter: I) {
+        for value in iter {
+            self.push(value);
+        }
+    }
+}
+
+impl<L, R> FromIterator<AnyOf<L, R>> for AnyOfVec<L, R> {
+    fn from_iter<I: IntoIterator<Item = AnyOf<L, R>>>(iter: I) -> Self {
+        let mut vec = Self::new();
+        vec.extend(iter);
+        vec
+    }
+}
+
+impl<L, R> From<Vec<AnyOf<L, R>>> for AnyOfVec<L, R> {
+    fn from(value: Vec<AnyOf<L, R>>) -> Self {
+        value.into_iter().collect()
+    }
+}
+
+impl<L, R> From<AnyOfVec<L, R>> for Vec<AnyOf<L, R>> {
+    /// See [AnyOfVec::into_vec].
+    fn from(value: AnyOfVec<L, R>) -> Self {
+        value.into_vec()
+    }
+}
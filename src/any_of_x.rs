@@ -125,6 +125,14 @@ impl<LL, LR, RL, RR> AnyOf4<LL, LR, RL, RR> {
     pub fn opt4(&self) -> Opt4<&LL, &LR, &RL, &RR> {
         (self.ll(), self.lr(), self.rl(), self.rr())
     }
+
+    /// Converts into an [`Opt4`] tuple, without cloning the values.
+    pub fn into_opt4(self) -> Opt4<LL, LR, RL, RR> {
+        let (left, right) = self.into_opt2();
+        let (ll, lr) = left.map_or((None, None), AnyOf::into_opt2);
+        let (rl, rr) = right.map_or((None, None), AnyOf::into_opt2);
+        (ll, lr, rl, rr)
+    }
 }
 
 /// A type representing a combination of eight possible types.
@@ -145,6 +153,7 @@ impl<LLL, LLR, LRL, LRR, RLL, RLR, RRL, RRR> AnyOf8<LLL, LLR, LRL, LRR, RLL, RLR
     /// );
     /// assert!(value.lll().is_some());
     /// ```
+    #[allow(clippy::too_many_arguments)]
     pub fn new8(
         lll: Option<LLL>,
         llr: Option<LLR>,
@@ -250,6 +259,14 @@ impl<LLL, LLR, LRL, LRR, RLL, RLR, RRL, RRR> AnyOf8<LLL, LLR, LRL, LRR, RLL, RLR
             self.rrr(),
         )
     }
+
+    /// Converts into an [`Opt8`] tuple, without cloning the values.
+    pub fn into_opt8(self) -> Opt8<LLL, LLR, LRL, LRR, RLL, RLR, RRL, RRR> {
+        let (left, right) = self.into_opt2();
+        let (lll, llr, lrl, lrr) = left.map_or_else(Default::default, AnyOf4::into_opt4);
+        let (rll, rlr, rrl, rrr) = right.map_or_else(Default::default, AnyOf4::into_opt4);
+        (lll, llr, lrl, lrr, rll, rlr, rrl, rrr)
+    }
 }
 
 /// A type representing a combination of sixteen possible types.
@@ -366,7 +383,7 @@ impl<
     /// let value = AnyOf16::from_opt16(tuple);
     /// assert_eq!(value.llll(), Some(&1));
     /// ```
-    #[allow(clippy::too_many_arguments)]
+    #[allow(clippy::too_many_arguments, clippy::type_complexity)]
     pub fn from_opt16(
         opt: Opt16<
             LLLL,
@@ -566,4 +583,37 @@ impl<
             self.rrrr(),
         )
     }
+
+    /// Converts into an [`Opt16`] tuple, without cloning the values.
+    #[allow(clippy::type_complexity)]
+    pub fn into_opt16(
+        self,
+    ) -> Opt16<
+        LLLL,
+        LLLR,
+        LLRL,
+        LLRR,
+        LRLL,
+        LRLR,
+        LRRL,
+        LRRR,
+        RLLL,
+        RLLR,
+        RLRL,
+        RLRR,
+        RRLL,
+        RRLR,
+        RRRL,
+        RRRR,
+    > {
+        let (left, right) = self.into_opt2();
+        let (llll, lllr, llrl, llrr, lrll, lrlr, lrrl, lrrr) =
+            left.map_or_else(Default::default, AnyOf8::into_opt8);
+        let (rlll, rllr, rlrl, rlrr, rrll, rrlr, rrrl, rrrr) =
+            right.map_or_else(Default::default, AnyOf8::into_opt8);
+        (
+            llll, lllr, llrl, llrr, lrll, lrlr, lrrl, lrrr,
+            rlll, rllr, rlrl, rlrr, rrll, rrlr, rrrl, rrrr,
+        )
+    }
 }
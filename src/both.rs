@@ -8,7 +8,8 @@
 //! ## `BothOf` Struct
 //!
 //! The `BothOf` struct is generic over two types `L` and `R`, allowing users to pair any two types together.
-//! It derives a variety of useful traits such as `Copy`, `Clone`, `Eq`, `PartialEq`, `Debug`, and `Hash`.
+//! It derives a variety of useful traits such as `Copy`, `Clone`, `Eq`, `PartialEq`, `Ord`, `PartialOrd`, `Debug`, and `Hash`.
+//! The ordering is lexicographic: `left` first, then `right`.
 //!
 //! ### Fields
 //! - `left`: The left value of type `L`.
@@ -49,8 +50,12 @@
 
 use crate::concepts::Swap;
 use crate::either::EitherOf;
-use crate::{Couple, LeftOrRight, Map, Unwrap};
+use crate::parse::DisplayValue;
+use crate::{Bifoldable, Bitraversable, Couple, LeftOrRight, Map, Unwrap};
+use core::error::Error;
+use core::fmt::{Display, Formatter};
 use core::ops::{Not, Shr};
+use core::pin::Pin;
 
 /// `BothOf` is a generic struct that allows pairing two values of potentially different types.
 ///
@@ -68,7 +73,35 @@ use core::ops::{Not, Shr};
 /// ```
 ///
 /// For more examples, see the documentation of the individual methods below.
-#[derive(Copy, Clone, Eq, PartialEq, Debug, Hash)]
+///
+/// # Layout
+///
+/// `BothOf` is `#[repr(C)]`: `left` is at offset 0, followed by `right` at its alignment.
+///
+/// - With the `bytemuck` feature, `BothOf<L, R>` is `Zeroable` when both sides are,
+///   and `BothOf<T, T>` is `Pod` when `T` is (two fields of the same type have no padding).
+/// - With the `zerocopy` feature, `BothOf` derives `FromBytes`, `Immutable`, `KnownLayout`,
+///   `Unaligned` and `IntoBytes` (the latter requires unaligned sides, e.g. byte arrays or
+///   `zerocopy::byteorder` integers, so that there is no padding).
+#[derive(Copy, Clone, Eq, PartialEq, Ord, PartialOrd, Debug, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "ufmt", derive(ufmt::derive::uDebug))]
+#[cfg_attr(
+    feature = "schemars",
+    derive(schemars::JsonSchema),
+    schemars(rename = "BothOf_{L}_and_{R}", description = "A left and a right value.")
+)]
+#[cfg_attr(
+    feature = "zerocopy",
+    derive(
+        zerocopy::FromBytes,
+        zerocopy::IntoBytes,
+        zerocopy::Immutable,
+        zerocopy::KnownLayout,
+        zerocopy::Unaligned
+    )
+)]
+#[repr(C)]
 pub struct BothOf<L, R = L> {
     pub left: L,
     pub right: R,
@@ -178,6 +211,32 @@ impl<L, R> BothOf<L, R> {
     pub fn into_right(self) -> EitherOf<L, R> {
         EitherOf::<L, R>::Right(self.right)
     }
+
+    /// Converts from `Pin<&BothOf<L, R>>` to `BothOf<Pin<&L>, Pin<&R>>`.
+    pub fn as_pin_ref(self: Pin<&Self>) -> BothOf<Pin<&L>, Pin<&R>> {
+        // SAFETY: the pinned values are only accessed through pinned references.
+        unsafe {
+            let both = self.get_ref();
+            BothOf {
+                left: Pin::new_unchecked(&both.left),
+                right: Pin::new_unchecked(&both.right),
+            }
+        }
+    }
+
+    /// Converts from `Pin<&mut BothOf<L, R>>` to `BothOf<Pin<&mut L>, Pin<&mut R>>`.
+    ///
+    /// This is the pin-projection of `BothOf`: both fields are pinned too.
+    pub fn as_pin_mut(self: Pin<&mut Self>) -> BothOf<Pin<&mut L>, Pin<&mut R>> {
+        // SAFETY: the fields are never moved out of the pinned `BothOf`.
+        unsafe {
+            let both = self.get_unchecked_mut();
+            BothOf {
+                left: Pin::new_unchecked(&mut both.left),
+                right: Pin::new_unchecked(&mut both.right),
+            }
+        }
+    }
 }
 
 impl<L, R> LeftOrRight<L, R> for BothOf<L, R> {
@@ -268,6 +327,36 @@ impl<L, R> Map<L, R> for BothOf<L, R> {
     }
 }
 
+impl<L, R> Bifoldable<L, R> for BothOf<L, R> {
+    fn bifold<A, FL, FR>(self, init: A, fl: FL, fr: FR) -> A
+    where
+        FL: FnOnce(A, L) -> A,
+        FR: FnOnce(A, R) -> A,
+    {
+        fr(fl(init, self.left), self.right)
+    }
+}
+
+impl<L, R> Bitraversable<L, R> for BothOf<L, R> {
+    fn traverse_option<L2, R2, FL, FR>(self, fl: FL, fr: FR) -> Option<Self::Output<L2, R2>>
+    where
+        FL: FnOnce(L) -> Option<L2>,
+        FR: FnOnce(R) -> Option<R2>,
+    {
+        let left = fl(self.left)?;
+        Some(BothOf::new(left, fr(self.right)?))
+    }
+
+    fn traverse_result<L2, R2, E, FL, FR>(self, fl: FL, fr: FR) -> Result<Self::Output<L2, R2>, E>
+    where
+        FL: FnOnce(L) -> Result<L2, E>,
+        FR: FnOnce(R) -> Result<R2, E>,
+    {
+        let left = fl(self.left)?;
+        Ok(BothOf::new(left, fr(self.right)?))
+    }
+}
+
 impl<L, R> Unwrap<L, R> for BothOf<L, R> {
     fn left_or_else(self, _: impl FnOnce() -> L) -> L {
         self.left
@@ -292,3 +381,96 @@ where
         }
     }
 }
+
+impl<L: Display, R: Display> Display for BothOf<L, R> {
+    /// Formats the value as `B(left, right)`.
+    ///
+    /// A value which would not parse back as is is escaped (see the [parse](crate::parse)
+    /// module).
+    ///
+    /// # Examples
+    /// ```rust
+    /// use any_of::BothOf;
+    ///
+    /// assert_eq!(BothOf::new(42, "Hello").to_string(), "B(42, Hello)");
+    /// ```
+    fn fmt(&self, f: &mut Formatter<'_>) -> core::fmt::Result {
+        let (left, right) = (DisplayValue(&self.left), DisplayValue(&self.right));
+        write!(f, "B({left}, {right})")
+    }
+}
+
+#[cfg(feature = "defmt")]
+impl<L: defmt::Format, R: defmt::Format> defmt::Format for BothOf<L, R> {
+    /// Formats the value as `B(left, right)`, like `Display`.
+    fn format(&self, f: defmt::Formatter<'_>) {
+        defmt::write!(f, "B({}, {})", self.left, self.right)
+    }
+}
+
+#[cfg(feature = "ufmt")]
+impl<L: ufmt::uDisplay, R: ufmt::uDisplay> ufmt::uDisplay for BothOf<L, R> {
+    /// Formats the value as `B(left, right)`, like `Display`.
+    fn fmt<W>(&self, f: &mut ufmt::Formatter<'_, W>) -> Result<(), W::Error>
+    where
+        W: ufmt::uWrite + ?Sized,
+    {
+        let (left, right) = (DisplayValue(&self.left), DisplayValue(&self.right));
+        ufmt::uwrite!(f, "B({}, {})", left, right)
+    }
+}
+
+// SAFETY: `BothOf` is `repr(C)`, and an all-zero `BothOf` is made of two all-zero sides.
+#[cfg(feature = "bytemuck")]
+unsafe impl<L: bytemuck::Zeroable, R: bytemuck::Zeroable> bytemuck::Zeroable for BothOf<L, R> {}
+
+// SAFETY: `BothOf` is `repr(C)` and both fields have the same size and alignment,
+// so there is no padding, and any bit pattern of two `Pod` values is valid.
+#[cfg(feature = "bytemuck")]
+unsafe impl<T: bytemuck::Pod> bytemuck::Pod for BothOf<T, T> {}
+
+impl<L: Error, R: Error> BothOf<L, R> {
+    /// Returns the source of the right error, which [BothOf::source](Error::source) only reports
+    /// when the left error has no source.
+    ///
+    /// # Examples
+    /// ```rust
+    /// use any_of::BothOf;
+    /// use core::error::Error;
+    /// use core::fmt::{self, Display, Formatter};
+    /// use core::num::ParseIntError;
+    ///
+    /// #[derive(Debug)]
+    /// struct Context<E>(E);
+    ///
+    /// impl<E> Display for Context<E> {
+    ///     fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+    ///         f.write_str("context")
+    ///     }
+    /// }
+    ///
+    /// impl<E: Error + 'static> Error for Context<E> {
+    ///     fn source(&self) -> Option<&(dyn Error + 'static)> {
+    ///         Some(&self.0)
+    ///     }
+    /// }
+    ///
+    /// let parse_error = "x".parse::<i32>().unwrap_err();
+    /// let both = BothOf::new(Context(fmt::Error), Context(parse_error));
+    /// assert!(both.source().unwrap().is::<fmt::Error>());
+    /// assert!(both.right_source().unwrap().is::<ParseIntError>());
+    /// ```
+    pub fn right_source(&self) -> Option<&(dyn Error + 'static)> {
+        self.right.source()
+    }
+}
+
+impl<L: Error, R: Error> Error for BothOf<L, R> {
+    /// Both errors are reported by the `Display` implementation.
+    ///
+    /// The source is the one of the left error if any, or else the one of the right error
+    /// (see [BothOf::right_source]).
+    fn source(&self) -> Option<&(dyn Error + 'static)> {
+        self.left.source().or_else(|| self.right.source())
+    }
+}
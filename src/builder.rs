@@ -0,0 +1,202 @@
+//! This module provides builders for [AnyOf8] and [AnyOf16], setting the leaves by name
+//! instead of the 8 or 16 positional arguments of [AnyOf8::new8] and [AnyOf16::new16].
+//!
+//! The builders are created with [AnyOf8::builder8] and [AnyOf16::builder16] (suffixed like
+//! [AnyOf8::new8] and [AnyOf16::new16], as an `AnyOf16` is also an `AnyOf8`); the leaves which
+//! are not set are absent, and `build()` returns the normalized value (a side without any
+//! leaf is absent rather than `Neither`).
+//!
+//! The [leaf_aliases](crate::leaf_aliases) macro gives domain names to the leaves of a
+//! specific instantiation.
+//!
+//! # Examples
+//!
+//! ```rust
+//! use any_of::AnyOf16;
+//!
+//! let value: AnyOf16<i32> = AnyOf16::builder16().lrrl(6).rrrr(15).build();
+//! assert_eq!(value.lrrl(), Some(&6));
+//! assert_eq!(value.rrrr(), Some(&15));
+//! assert_eq!(value.llll(), None);
+//! ```
+
+use crate::concepts::{Opt8, Opt16};
+use crate::{AnyOf8, AnyOf16};
+
+macro_rules! any_of_builder {
+    (
+        $(#[$meta:meta])*
+        $name:ident [$($decl:tt)*], $nested:ident, $opt:ident, $from_opt:ident, $new_builder:ident;
+        $($T:ident $leaf:ident),+ $(,)?
+    ) => {
+        $(#[$meta])*
+        #[derive(Copy, Clone, Eq, PartialEq, Debug, Hash)]
+        pub struct $name<$($decl)*> {
+            $($leaf: Option<$T>,)+
+        }
+
+        impl<$($T),+> $name<$($T),+> {
+            /// Creates a builder without any leaf.
+            pub fn new() -> Self {
+                Self { $($leaf: None,)+ }
+            }
+
+            #[doc = concat!("Creates a builder from an [", stringify!($opt), "] tuple.")]
+            #[allow(clippy::type_complexity)]
+            pub fn from_opt(opt: $opt<$($T),+>) -> Self {
+                let ($($leaf,)+) = opt;
+                Self { $($leaf,)+ }
+            }
+
+            $(
+                #[doc = concat!("Sets the `", stringify!($leaf), "` leaf.")]
+                pub fn $leaf(mut self, value: $T) -> Self {
+                    self.$leaf = Some(value);
+                    self
+                }
+            )+
+
+            #[doc = concat!("Builds the normalized [", stringify!($nested), "].")]
+            pub fn build(self) -> $nested<$($T),+> {
+                $nested::$from_opt(($(self.$leaf,)+))
+            }
+        }
+
+        impl<$($T),+> Default for $name<$($T),+> {
+            fn default() -> Self {
+                Self::new()
+            }
+        }
+
+        impl<$($T),+> $nested<$($T),+> {
+            #[doc = concat!("Returns a [", stringify!($name), "] setting the leaves by name.")]
+            pub fn $new_builder() -> $name<$($T),+> {
+                $name::new()
+            }
+        }
+
+        impl<$($T),+> From<$name<$($T),+>> for $nested<$($T),+> {
+            #[doc = concat!("See [", stringify!($name), "::build].")]
+            fn from(builder: $name<$($T),+>) -> Self {
+                builder.build()
+            }
+        }
+    };
+}
+
+any_of_builder! {
+    /// A builder of [AnyOf8], created with [AnyOf8::builder8].
+    ///
+    /// See the [module documentation](self).
+    AnyOf8Builder [
+        LLL,
+        LLR = LLL,
+        LRL = LLR,
+        LRR = LRL,
+        RLL = LLL,
+        RLR = LLR,
+        RRL = LRL,
+        RRR = LRR,
+    ], AnyOf8, Opt8, from_opt8, builder8;
+    LLL lll,
+    LLR llr,
+    LRL lrl,
+    LRR lrr,
+    RLL rll,
+    RLR rlr,
+    RRL rrl,
+    RRR rrr,
+}
+
+any_of_builder! {
+    /// A builder of [AnyOf16], created with [AnyOf16::builder16].
+    ///
+    /// See the [module documentation](self).
+    AnyOf16Builder [
+        LLLL,
+        LLLR = LLLL,
+        LLRL = LLLR,
+        LLRR = LLRL,
+        LRLL = LLLL,
+        LRLR = LLLR,
+        LRRL = LLRL,
+        LRRR = LLRR,
+        RLLL = LLLL,
+        RLLR = LLLR,
+        RLRL = LLRL,
+        RLRR = LLRR,
+        RRLL = LRLL,
+        RRLR = LRLR,
+        RRRL = LRRL,
+        RRRR = LRRR,
+    ], AnyOf16, Opt16, from_opt16, builder16;
+    LLLL llll,
+    LLLR lllr,
+    LLRL llrl,
+    LLRR llrr,
+    LRLL lrll,
+    LRLR lrlr,
+    LRRL lrrl,
+    LRRR lrrr,
+    RLLL rlll,
+    RLLR rllr,
+    RLRL rlrl,
+    RLRR rlrr,
+    RRLL rrll,
+    RRLR rrlr,
+    RRRL rrrl,
+    RRRR rrrr,
+}
+
+/// Declares a trait giving domain names to the leaf setters of a builder
+/// ([AnyOf8Builder] or [AnyOf16Builder]), and implements it for a specific instantiation.
+///
+/// Each alias is written `name: Type = leaf`, and calls the setter of `leaf`.
+///
+/// # Examples
+/// ```rust
+/// use any_of::builder::AnyOf8Builder;
+/// use any_of::{leaf_aliases, AnyOf8};
+///
+/// type Options = AnyOf8<u64, u32, bool, &'static str>;
+///
+/// leaf_aliases! {
+///     /// The named options of a request.
+///     pub trait RequestOptions for AnyOf8Builder<u64, u32, bool, &'static str> {
+///         /// The timeout, in milliseconds.
+///         timeout: u64 = lll,
+///         retries: u32 = llr,
+///         user_agent: &'static str = rrr,
+///     }
+/// }
+///
+/// let options: Options = Options::builder8().timeout(500).user_agent("any_of").build();
+/// assert_eq!(options.lll(), Some(&500));
+/// assert_eq!(options.rrr(), Some(&"any_of"));
+/// assert_eq!(options.llr(), None);
+/// ```
+#[macro_export]
+macro_rules! leaf_aliases {
+    (
+        $(#[$meta:meta])*
+        $vis:vis trait $name:ident for $builder:ty {
+            $($(#[$alias_meta:meta])* $alias:ident : $T:ty = $leaf:ident),* $(,)?
+        }
+    ) => {
+        $(#[$meta])*
+        $vis trait $name {
+            $(
+                $(#[$alias_meta])*
+                fn $alias(self, value: $T) -> Self;
+            )*
+        }
+
+        impl $name for $builder {
+            $(
+                fn $alias(self, value: $T) -> Self {
+                    <$builder>::$leaf(self, value)
+                }
+            )*
+        }
+    };
+}
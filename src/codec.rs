@@ -0,0 +1,398 @@
+//! This module provides a compact binary encoding of [AnyOf], [EitherOf] and [BothOf],
+//! through the [Encode] and [Decode] traits.
+//!
+//! # Format
+//!
+//! - Integers (`u8` to `u128`, `i8` to `i128`) are encoded in little-endian, `bool` as one byte,
+//! - Byte slices are encoded as their length (LEB128 varint) followed by the bytes,
+//! - `BothOf<L, R>` is encoded as the left value followed by the right value,
+//! - `EitherOf<L, R>` is encoded as a tag byte (`0b01` for left, `0b10` for right) followed by the value,
+//! - `AnyOf<L, R>` is encoded as a shape header followed by the present values only.
+//!
+//! The shape header of an `AnyOf` is a presence bitmask over its leaves, in little-endian:
+//! the nested `AnyOf` sides share the header of the outermost `AnyOf`.
+//! The bit `i` is set when the `i`-th leaf is present (the left leaves first), like the shape of
+//! [crate::FlatAnyOf8]. An `AnyOf`, [AnyOf4] and [AnyOf8] have a one-byte header,
+//! and [AnyOf16] a two-byte header: a sparse `AnyOf16` only costs its present leaves and two bytes.
+//!
+//! As the nested sides have no header of their own, a nested `Neither` (like `Left(Neither)`)
+//! is decoded as an absent side.
+//!
+//! The header is at most 4 bytes: encoding or decoding an `AnyOf` of more than 32 leaves does
+//! not compile.
+//!
+//! # Examples
+//!
+//! ```rust
+//! use any_of::codec::{Decode, Encode};
+//! use any_of::{AnyOf, AnyOf4, AnyOf8, AnyOf16};
+//!
+//! let sparse: AnyOf16<u16> = AnyOf16::new_right(AnyOf8::new_left(AnyOf4::new_right(AnyOf::new_left(7))));
+//! let mut buf = [0; 8];
+//! let len = sparse.encode(&mut buf).unwrap();
+//! assert_eq!(&buf[..len], &[0, 0b0000_0100, 7, 0]);
+//! assert_eq!(AnyOf16::<u16>::decode(&buf[..len]), Ok((sparse, len)));
+//! ```
+//!
+//! 33 leaves are one too many:
+//! ```rust,compile_fail
+//! use any_of::codec::Encode;
+//! use any_of::{AnyOf, AnyOf16};
+//!
+//! let any: AnyOf<AnyOf<AnyOf16<u8>, AnyOf16<u8>>, u8> = AnyOf::new_right(1);
+//! any.encode(&mut [0; 8]).unwrap();
+//! ```
+
+use crate::{AnyOf, BothOf, EitherOf, Left, LeftOrRight, Right};
+#[cfg(doc)]
+use crate::{AnyOf4, AnyOf8, AnyOf16};
+use core::error::Error;
+use core::fmt::{Display, Formatter};
+
+/// The errors of [Encode] and [Decode].
+#[derive(Copy, Clone, Eq, PartialEq, Debug, Hash)]
+pub enum CodecError {
+    /// The buffer is too small to encode the value.
+    BufferTooSmall,
+    /// The buffer ends before the end of the encoded value.
+    UnexpectedEnd,
+    /// The shape header (or the tag of an `EitherOf`) is not valid for the decoded type.
+    InvalidShape(u32),
+    /// The byte of a `bool` is neither `0` nor `1`.
+    InvalidBool(u8),
+    /// The varint length of a byte slice does not fit in a `usize`.
+    LengthOverflow,
+}
+
+impl Display for CodecError {
+    fn fmt(&self, f: &mut Formatter<'_>) -> core::fmt::Result {
+        match self {
+            CodecError::BufferTooSmall => f.write_str("buffer too small"),
+            CodecError::UnexpectedEnd => f.write_str("unexpected end of buffer"),
+            CodecError::InvalidShape(shape) => write!(f, "invalid shape {shape:#b}"),
+            CodecError::InvalidBool(byte) => write!(f, "invalid bool {byte}"),
+            CodecError::LengthOverflow => f.write_str("length overflow"),
+        }
+    }
+}
+
+impl Error for CodecError {}
+
+/// Encodes a value into a byte buffer.
+///
+/// Only [Encode::encoded_len] and [Encode::encode] are meant to be implemented:
+/// the other items let a nested `AnyOf` share the shape header of the outermost `AnyOf`.
+pub trait Encode {
+    /// The number of leaves of the value in the shape header of an enclosing `AnyOf`.
+    const LEAVES: u32 = 1;
+
+    /// Returns the number of bytes written by [Encode::encode].
+    fn encoded_len(&self) -> usize;
+
+    /// Encodes the value at the start of `buf`, and returns the number of bytes written.
+    fn encode(&self, buf: &mut [u8]) -> Result<usize, CodecError>;
+
+    /// Returns the presence bitmask of the leaves of the value.
+    fn shape(&self) -> u32 {
+        1
+    }
+
+    /// Returns the number of bytes written by [Encode::encode_leaves].
+    fn leaves_len(&self) -> usize {
+        self.encoded_len()
+    }
+
+    /// Encodes the present leaves of the value, without a shape header.
+    fn encode_leaves(&self, buf: &mut [u8]) -> Result<usize, CodecError> {
+        self.encode(buf)
+    }
+}
+
+/// Decodes a value from a byte buffer, possibly borrowing from it.
+///
+/// Only [Decode::decode] is meant to be implemented:
+/// the other items let a nested `AnyOf` share the shape header of the outermost `AnyOf`.
+pub trait Decode<'a>: Sized {
+    /// The number of leaves of the value in the shape header of an enclosing `AnyOf`.
+    const LEAVES: u32 = 1;
+
+    /// Decodes a value from the start of `buf`, and returns it with the number of bytes read.
+    fn decode(buf: &'a [u8]) -> Result<(Self, usize), CodecError>;
+
+    /// Decodes the leaves present in `shape`, written without a shape header.
+    fn decode_leaves(shape: u32, buf: &'a [u8]) -> Result<(Option<Self>, usize), CodecError> {
+        if shape & 1 == 0 {
+            return Ok((None, 0));
+        }
+        let (value, len) = Self::decode(buf)?;
+        Ok((Some(value), len))
+    }
+}
+
+fn write(buf: &mut [u8], bytes: &[u8]) -> Result<usize, CodecError> {
+    buf.get_mut(..bytes.len())
+        .ok_or(CodecError::BufferTooSmall)?
+        .copy_from_slice(bytes);
+    Ok(bytes.len())
+}
+
+fn read(buf: &[u8], len: usize) -> Result<&[u8], CodecError> {
+    buf.get(..len).ok_or(CodecError::UnexpectedEnd)
+}
+
+macro_rules! codec_integer {
+    ($($int:ty),+) => {
+        $(
+            impl Encode for $int {
+                fn encoded_len(&self) -> usize {
+                    size_of::<$int>()
+                }
+
+                fn encode(&self, buf: &mut [u8]) -> Result<usize, CodecError> {
+                    write(buf, &self.to_le_bytes())
+                }
+            }
+
+            impl Decode<'_> for $int {
+                fn decode(buf: &[u8]) -> Result<(Self, usize), CodecError> {
+                    const LEN: usize = size_of::<$int>();
+                    let mut bytes = [0; LEN];
+                    bytes.copy_from_slice(read(buf, LEN)?);
+                    Ok((<$int>::from_le_bytes(bytes), LEN))
+                }
+            }
+        )+
+    };
+}
+
+codec_integer!(u8, u16, u32, u64, u128, i8, i16, i32, i64, i128);
+
+impl Encode for bool {
+    fn encoded_len(&self) -> usize {
+        1
+    }
+
+    fn encode(&self, buf: &mut [u8]) -> Result<usize, CodecError> {
+        write(buf, &[u8::from(*self)])
+    }
+}
+
+impl Decode<'_> for bool {
+    fn decode(buf: &[u8]) -> Result<(Self, usize), CodecError> {
+        match read(buf, 1)?[0] {
+            0 => Ok((false, 1)),
+            1 => Ok((true, 1)),
+            byte => Err(CodecError::InvalidBool(byte)),
+        }
+    }
+}
+
+fn varint_len(mut n: usize) -> usize {
+    let mut len = 1;
+    while n >= 0x80 {
+        n >>= 7;
+        len += 1;
+    }
+    len
+}
+
+fn encode_varint(mut n: usize, buf: &mut [u8]) -> Result<usize, CodecError> {
+    for (i, slot) in buf.iter_mut().enumerate() {
+        let byte = (n & 0x7f) as u8;
+        n >>= 7;
+        if n == 0 {
+            *slot = byte;
+            return Ok(i + 1);
+        }
+        *slot = byte | 0x80;
+    }
+    Err(CodecError::BufferTooSmall)
+}
+
+fn decode_varint(buf: &[u8]) -> Result<(usize, usize), CodecError> {
+    let mut n = 0usize;
+    for (i, &byte) in buf.iter().enumerate() {
+        let bits = usize::from(byte & 0x7f);
+        let shift = 7 * i as u32;
+        n |= bits
+            .checked_shl(shift)
+            .filter(|shifted| shifted >> shift == bits)
+            .ok_or(CodecError::LengthOverflow)?;
+        if byte & 0x80 == 0 {
+            return Ok((n, i + 1));
+        }
+    }
+    Err(CodecError::UnexpectedEnd)
+}
+
+impl Encode for [u8] {
+    fn encoded_len(&self) -> usize {
+        varint_len(self.len()) + self.len()
+    }
+
+    fn encode(&self, buf: &mut [u8]) -> Result<usize, CodecError> {
+        let len = encode_varint(self.len(), buf)?;
+        Ok(len + write(&mut buf[len..], self)?)
+    }
+}
+
+impl<'a> Decode<'a> for &'a [u8] {
+    fn decode(buf: &'a [u8]) -> Result<(Self, usize), CodecError> {
+        let (len, header) = decode_varint(buf)?;
+        let bytes = read(&buf[header..], len)?;
+        Ok((bytes, header + len))
+    }
+}
+
+impl<T: Encode + ?Sized> Encode for &T {
+    const LEAVES: u32 = T::LEAVES;
+
+    fn encoded_len(&self) -> usize {
+        T::encoded_len(self)
+    }
+
+    fn encode(&self, buf: &mut [u8]) -> Result<usize, CodecError> {
+        T::encode(self, buf)
+    }
+
+    fn shape(&self) -> u32 {
+        T::shape(self)
+    }
+
+    fn leaves_len(&self) -> usize {
+        T::leaves_len(self)
+    }
+
+    fn encode_leaves(&self, buf: &mut [u8]) -> Result<usize, CodecError> {
+        T::encode_leaves(self, buf)
+    }
+}
+
+impl<L: Encode, R: Encode> Encode for BothOf<L, R> {
+    fn encoded_len(&self) -> usize {
+        self.left.encoded_len() + self.right.encoded_len()
+    }
+
+    fn encode(&self, buf: &mut [u8]) -> Result<usize, CodecError> {
+        let len = self.left.encode(buf)?;
+        Ok(len + self.right.encode(&mut buf[len..])?)
+    }
+}
+
+impl<'a, L: Decode<'a>, R: Decode<'a>> Decode<'a> for BothOf<L, R> {
+    fn decode(buf: &'a [u8]) -> Result<(Self, usize), CodecError> {
+        let (left, left_len) = L::decode(buf)?;
+        let (right, right_len) = R::decode(&buf[left_len..])?;
+        Ok((BothOf::new(left, right), left_len + right_len))
+    }
+}
+
+const LEFT_TAG: u8 = 0b01;
+const RIGHT_TAG: u8 = 0b10;
+
+impl<L: Encode, R: Encode> Encode for EitherOf<L, R> {
+    fn encoded_len(&self) -> usize {
+        1 + match self {
+            Left(l) => l.encoded_len(),
+            Right(r) => r.encoded_len(),
+        }
+    }
+
+    fn encode(&self, buf: &mut [u8]) -> Result<usize, CodecError> {
+        match self {
+            Left(l) => {
+                let len = write(buf, &[LEFT_TAG])?;
+                Ok(len + l.encode(&mut buf[len..])?)
+            }
+            Right(r) => {
+                let len = write(buf, &[RIGHT_TAG])?;
+                Ok(len + r.encode(&mut buf[len..])?)
+            }
+        }
+    }
+}
+
+impl<'a, L: Decode<'a>, R: Decode<'a>> Decode<'a> for EitherOf<L, R> {
+    fn decode(buf: &'a [u8]) -> Result<(Self, usize), CodecError> {
+        let rest = &buf[read(buf, 1)?.len()..];
+        let (either, len) = match buf[0] {
+            LEFT_TAG => L::decode(rest).map(|(l, len)| (Left(l), len))?,
+            RIGHT_TAG => R::decode(rest).map(|(r, len)| (Right(r), len))?,
+            tag => return Err(CodecError::InvalidShape(u32::from(tag))),
+        };
+        Ok((either, 1 + len))
+    }
+}
+
+/// Returns the number of bytes of the shape header of `T`, failing to compile beyond 32 leaves.
+fn encode_header_len<T: Encode>() -> usize {
+    const { assert!(T::LEAVES <= u32::BITS, "an AnyOf is limited to 32 leaves") };
+    T::LEAVES.div_ceil(8) as usize
+}
+
+/// Returns the number of bytes of the shape header of `T`, failing to compile beyond 32 leaves.
+fn decode_header_len<'a, T: Decode<'a>>() -> usize {
+    const { assert!(T::LEAVES <= u32::BITS, "an AnyOf is limited to 32 leaves") };
+    T::LEAVES.div_ceil(8) as usize
+}
+
+impl<L: Encode, R: Encode> Encode for AnyOf<L, R> {
+    const LEAVES: u32 = L::LEAVES + R::LEAVES;
+
+    fn encoded_len(&self) -> usize {
+        encode_header_len::<Self>() + self.leaves_len()
+    }
+
+    fn encode(&self, buf: &mut [u8]) -> Result<usize, CodecError> {
+        let header_len = encode_header_len::<Self>();
+        let len = write(buf, &self.shape().to_le_bytes()[..header_len])?;
+        Ok(len + self.encode_leaves(&mut buf[len..])?)
+    }
+
+    fn shape(&self) -> u32 {
+        let (left, right) = self.opt2();
+        let right_shape = right
+            .map_or(0, R::shape)
+            .checked_shl(L::LEAVES)
+            .unwrap_or(0);
+        left.map_or(0, L::shape) | right_shape
+    }
+
+    fn leaves_len(&self) -> usize {
+        let (left, right) = self.opt2();
+        left.map_or(0, L::leaves_len) + right.map_or(0, R::leaves_len)
+    }
+
+    fn encode_leaves(&self, buf: &mut [u8]) -> Result<usize, CodecError> {
+        let (left, right) = self.opt2();
+        let len = left.map_or(Ok(0), |l| l.encode_leaves(buf))?;
+        Ok(len + right.map_or(Ok(0), |r| r.encode_leaves(&mut buf[len..]))?)
+    }
+}
+
+impl<'a, L: Decode<'a>, R: Decode<'a>> Decode<'a> for AnyOf<L, R> {
+    const LEAVES: u32 = L::LEAVES + R::LEAVES;
+
+    fn decode(buf: &'a [u8]) -> Result<(Self, usize), CodecError> {
+        let leaves = <Self as Decode>::LEAVES;
+        let header = read(buf, decode_header_len::<Self>())?;
+        let shape = header
+            .iter()
+            .rev()
+            .fold(0u32, |shape, &byte| shape << 8 | u32::from(byte));
+        if shape.checked_shr(leaves).unwrap_or(0) != 0 {
+            return Err(CodecError::InvalidShape(shape));
+        }
+        let (any, len) = Self::decode_leaves(shape, &buf[header.len()..])?;
+        Ok((any.unwrap_or(AnyOf::Neither), header.len() + len))
+    }
+
+    fn decode_leaves(shape: u32, buf: &'a [u8]) -> Result<(Option<Self>, usize), CodecError> {
+        let left_mask = 1u32.checked_shl(L::LEAVES).map_or(u32::MAX, |bit| bit - 1);
+        let (left, left_len) = L::decode_leaves(shape & left_mask, buf)?;
+        let right_shape = shape.checked_shr(L::LEAVES).unwrap_or(0);
+        let (right, right_len) = R::decode_leaves(right_shape, &buf[left_len..])?;
+        let any = AnyOf::new(left, right);
+        Ok(((!any.is_neither()).then_some(any), left_len + right_len))
+    }
+}
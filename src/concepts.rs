@@ -524,3 +524,137 @@ pub trait Unwrap<L, R>: LeftOrRight<L, R> {
         self.expect_right("called `unwrap_right` on `LeftOrRight` value that is `Left`")
     }
 }
+
+/// The `Bifoldable` trait folds the present values of a dual-variant type (`LeftOrRight`)
+/// into a single value, in left-then-right order.
+///
+/// For the `AnyOfX` aliases, the two sides are the two nested halves.
+///
+/// # Examples
+/// ```rust
+/// use any_of::{AnyOf, Bifoldable, BothOf, EitherOf, Left};
+///
+/// fn total(value: impl Bifoldable<u32, u32>) -> u32 {
+///     value.bifold(0, |acc, l| acc + l, |acc, r| acc + r)
+/// }
+///
+/// assert_eq!(total(BothOf::new(1, 2)), 3);
+/// assert_eq!(total(AnyOf::new_right(2)), 2);
+/// assert_eq!(total(EitherOf::<u32, u32>::Left(1)), 1);
+/// ```
+pub trait Bifoldable<L, R>: LeftOrRight<L, R> {
+    /// Folds the left value (if present) with `fl`, then the right value (if present) with `fr`,
+    /// starting from `init`.
+    fn bifold<A, FL, FR>(self, init: A, fl: FL, fr: FR) -> A
+    where
+        FL: FnOnce(A, L) -> A,
+        FR: FnOnce(A, R) -> A;
+
+    /// Maps the present values into a [Semigroup] and merges them, left then right.
+    ///
+    /// Returns `None` if no value is present.
+    fn bifold_map<M, FL, FR>(self, fl: FL, fr: FR) -> Option<M>
+    where
+        Self: Sized,
+        M: Semigroup,
+        FL: FnOnce(L) -> M,
+        FR: FnOnce(R) -> M,
+    {
+        self.bifold(
+            None,
+            |acc: Option<M>, l| acc.append(Some(fl(l))),
+            |acc, r| acc.append(Some(fr(r))),
+        )
+    }
+
+    /// Returns the number of present values.
+    fn bicount(self) -> usize
+    where
+        Self: Sized,
+    {
+        self.bifold(0, |n, _| n + 1, |n, _| n + 1)
+    }
+}
+
+/// The `Bitraversable` trait applies effectful functions (returning an `Option` or a `Result`)
+/// to the present values of a dual-variant type, and sequences the effects.
+///
+/// The functions are called in left-then-right order, and the traversal stops at the first
+/// `None` or `Err`: the right function is not called if the left one fails.
+///
+/// For the `AnyOfX` aliases, the two sides are the two nested halves.
+///
+/// # Examples
+/// ```rust
+/// use any_of::{AnyOf, Bitraversable};
+///
+/// let any: AnyOf<&str, &str> = AnyOf::new_both("1", "2");
+/// let parsed = any.traverse_result(str::parse::<u8>, str::parse::<u16>);
+/// assert_eq!(parsed, Ok(AnyOf::new_both(1, 2)));
+///
+/// let any: AnyOf<&str, &str> = AnyOf::new_right("x");
+/// assert!(any.traverse_result(str::parse::<u8>, str::parse::<u16>).is_err());
+/// ```
+pub trait Bitraversable<L, R>: Map<L, R> {
+    /// Applies `fl` and `fr` to the present values, and returns `None` if any of them does.
+    fn traverse_option<L2, R2, FL, FR>(self, fl: FL, fr: FR) -> Option<Self::Output<L2, R2>>
+    where
+        FL: FnOnce(L) -> Option<L2>,
+        FR: FnOnce(R) -> Option<R2>;
+
+    /// Applies `fl` and `fr` to the present values, and returns the first error if any.
+    fn traverse_result<L2, R2, E, FL, FR>(self, fl: FL, fr: FR) -> Result<Self::Output<L2, R2>, E>
+    where
+        FL: FnOnce(L) -> Result<L2, E>,
+        FR: FnOnce(R) -> Result<R2, E>;
+}
+
+/// The `Semigroup` trait describes types whose values can be merged together
+/// with an associative operation.
+///
+/// It is used by the [crate::validate] module to accumulate diagnostics.
+///
+/// # Examples
+/// ```rust
+/// use any_of::Semigroup;
+///
+/// assert_eq!(Some(()).append(None), Some(()));
+/// ```
+pub trait Semigroup {
+    /// Merges `other` after `self`.
+    ///
+    /// The operation must be associative:
+    /// `a.append(b).append(c) == a.append(b.append(c))`.
+    fn append(self, other: Self) -> Self;
+}
+
+impl Semigroup for () {
+    fn append(self, _: Self) -> Self {}
+}
+
+impl<S: Semigroup> Semigroup for Option<S> {
+    /// `None` is the identity element: the other operand is returned.
+    fn append(self, other: Self) -> Self {
+        match (self, other) {
+            (Some(a), Some(b)) => Some(a.append(b)),
+            (a, None) => a,
+            (None, b) => b,
+        }
+    }
+}
+
+#[cfg(feature = "alloc")]
+impl<T> Semigroup for alloc::vec::Vec<T> {
+    fn append(mut self, mut other: Self) -> Self {
+        alloc::vec::Vec::append(&mut self, &mut other);
+        self
+    }
+}
+
+#[cfg(feature = "alloc")]
+impl Semigroup for alloc::string::String {
+    fn append(mut self, other: Self) -> Self {
+        self.push_str(&other);
+        self
+    }
+}
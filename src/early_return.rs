@@ -0,0 +1,276 @@
+//! This module provides `?`-like early returns for [EitherOf] and [AnyOf] on stable Rust.
+//!
+//! # Conversions to `Result`
+//!
+//! - [EitherOf::ok_or_left] and [AnyOf::ok_or_left]: the right value is `Ok`,
+//! - [EitherOf::ok_or_right] and [AnyOf::ok_or_right]: the left value is `Ok`.
+//!
+//! The resulting `Result` can be used with the `?` operator.
+//!
+//! # Macros
+//!
+//! - [crate::right_or_return]: evaluates to the right value, or returns the rest early,
+//! - [crate::left_or_return]: evaluates to the left value, or returns the rest early.
+//!
+//! The value returned early is of the same kind as the input ([EitherOf] or [AnyOf]),
+//! the other side being converted with `From`.
+//!
+//! ```rust
+//! use any_of::{right_or_return, AnyOf};
+//!
+//! fn double(value: AnyOf<&'static str, i32>) -> AnyOf<&'static str, i32> {
+//!     let right = right_or_return!(value);
+//!     AnyOf::new_right(right * 2)
+//! }
+//!
+//! assert_eq!(double(AnyOf::new_both("ignored", 21)), AnyOf::new_right(42));
+//! assert_eq!(double(AnyOf::new_left("error")), AnyOf::new_left("error"));
+//! assert_eq!(double(AnyOf::new_neither()), AnyOf::new_neither());
+//! ```
+//!
+//! # Nightly `Try`
+//!
+//! With the `try_trait` feature (nightly only), `core::ops::Try` is implemented for
+//! [EitherOf] and [AnyOf], with the right value (of `Right` or `Both`) as the output.
+
+use crate::{AnyOf, Both, BothOf, Either, EitherOf, Left, Neither, Right};
+
+/// Splits a value between the side to keep and the value to return early.
+///
+/// This trait is used by the [crate::right_or_return] and [crate::left_or_return] macros.
+pub trait EarlyReturn<L, R> {
+    /// The type of the value returned early.
+    type Output<L2, R2>;
+
+    /// Returns the right value, or the value to return early with the left value converted.
+    fn right_or_residual<L2: From<L>, R2>(self) -> Result<R, Self::Output<L2, R2>>;
+
+    /// Returns the left value, or the value to return early with the right value converted.
+    fn left_or_residual<L2, R2: From<R>>(self) -> Result<L, Self::Output<L2, R2>>;
+}
+
+impl<L, R> EarlyReturn<L, R> for EitherOf<L, R> {
+    type Output<L2, R2> = EitherOf<L2, R2>;
+
+    fn right_or_residual<L2: From<L>, R2>(self) -> Result<R, Self::Output<L2, R2>> {
+        match self {
+            Left(l) => Err(Left(l.into())),
+            Right(r) => Ok(r),
+        }
+    }
+
+    fn left_or_residual<L2, R2: From<R>>(self) -> Result<L, Self::Output<L2, R2>> {
+        match self {
+            Left(l) => Ok(l),
+            Right(r) => Err(Right(r.into())),
+        }
+    }
+}
+
+impl<L, R> EarlyReturn<L, R> for AnyOf<L, R> {
+    type Output<L2, R2> = AnyOf<L2, R2>;
+
+    fn right_or_residual<L2: From<L>, R2>(self) -> Result<R, Self::Output<L2, R2>> {
+        match self {
+            Neither => Err(Neither),
+            Either(Left(l)) => Err(Either(Left(l.into()))),
+            Either(Right(r)) | Both(BothOf { right: r, .. }) => Ok(r),
+        }
+    }
+
+    fn left_or_residual<L2, R2: From<R>>(self) -> Result<L, Self::Output<L2, R2>> {
+        match self {
+            Neither => Err(Neither),
+            Either(Right(r)) => Err(Either(Right(r.into()))),
+            Either(Left(l)) | Both(BothOf { left: l, .. }) => Ok(l),
+        }
+    }
+}
+
+/// Evaluates to the right value of an [EitherOf] or an [AnyOf], or returns early.
+///
+/// - `EitherOf`: `Left(l)` returns `Left(l.into())`,
+/// - `AnyOf`: `Left(l)` returns `Either(Left(l.into()))` and `Neither` returns `Neither`,
+///   `Both` evaluates to its right value.
+///
+/// # Examples
+/// ```rust
+/// use any_of::{right_or_return, EitherOf, Left, Right};
+///
+/// fn length(value: EitherOf<u8, &str>) -> EitherOf<u32, usize> {
+///     Right(right_or_return!(value).len())
+/// }
+///
+/// assert_eq!(length(Right("four")), Right(4));
+/// assert_eq!(length(Left(1)), Left(1));
+/// ```
+#[macro_export]
+macro_rules! right_or_return {
+    ($value:expr) => {
+        match $crate::early_return::EarlyReturn::right_or_residual($value) {
+            ::core::result::Result::Ok(right) => right,
+            ::core::result::Result::Err(residual) => return residual,
+        }
+    };
+}
+
+/// Evaluates to the left value of an [EitherOf] or an [AnyOf], or returns early.
+///
+/// This is the mirror of [crate::right_or_return].
+///
+/// # Examples
+/// ```rust
+/// use any_of::{left_or_return, AnyOf};
+///
+/// fn first(value: AnyOf<i32, &'static str>) -> AnyOf<i32, &'static str> {
+///     AnyOf::new_left(left_or_return!(value) + 1)
+/// }
+///
+/// assert_eq!(first(AnyOf::new_both(41, "ignored")), AnyOf::new_left(42));
+/// assert_eq!(first(AnyOf::new_right("error")), AnyOf::new_right("error"));
+/// ```
+#[macro_export]
+macro_rules! left_or_return {
+    ($value:expr) => {
+        match $crate::early_return::EarlyReturn::left_or_residual($value) {
+            ::core::result::Result::Ok(left) => left,
+            ::core::result::Result::Err(residual) => return residual,
+        }
+    };
+}
+
+impl<L, R> EitherOf<L, R> {
+    /// Converts to a `Result` with the right value as `Ok` and the left value as `Err`.
+    ///
+    /// # Examples
+    /// ```rust
+    /// use any_of::{EitherOf, Left, Right};
+    ///
+    /// fn parse(value: EitherOf<&'static str, i32>) -> Result<i32, &'static str> {
+    ///     Ok(value.ok_or_left()? * 2)
+    /// }
+    ///
+    /// assert_eq!(parse(Right(21)), Ok(42));
+    /// assert_eq!(parse(Left("error")), Err("error"));
+    /// ```
+    pub fn ok_or_left(self) -> Result<R, L> {
+        match self {
+            Left(l) => Err(l),
+            Right(r) => Ok(r),
+        }
+    }
+
+    /// Converts to a `Result` with the left value as `Ok` and the right value as `Err`.
+    pub fn ok_or_right(self) -> Result<L, R> {
+        match self {
+            Left(l) => Ok(l),
+            Right(r) => Err(r),
+        }
+    }
+}
+
+impl<L, R> AnyOf<L, R> {
+    /// Converts to a `Result` with the right value (of `Right` or `Both`) as `Ok`.
+    ///
+    /// `Left(l)` is converted to `Err(Some(l))` and `Neither` to `Err(None)`.
+    ///
+    /// # Examples
+    /// ```rust
+    /// use any_of::AnyOf;
+    ///
+    /// assert_eq!(AnyOf::new_both(1, 2).ok_or_left(), Ok(2));
+    /// assert_eq!(AnyOf::<i32, i32>::new_left(1).ok_or_left(), Err(Some(1)));
+    /// assert_eq!(AnyOf::<i32, i32>::new_neither().ok_or_left(), Err(None));
+    /// ```
+    pub fn ok_or_left(self) -> Result<R, Option<L>> {
+        match self {
+            Neither => Err(None),
+            Either(Left(l)) => Err(Some(l)),
+            Either(Right(r)) | Both(BothOf { right: r, .. }) => Ok(r),
+        }
+    }
+
+    /// Converts to a `Result` with the left value (of `Left` or `Both`) as `Ok`.
+    ///
+    /// `Right(r)` is converted to `Err(Some(r))` and `Neither` to `Err(None)`.
+    pub fn ok_or_right(self) -> Result<L, Option<R>> {
+        match self {
+            Neither => Err(None),
+            Either(Right(r)) => Err(Some(r)),
+            Either(Left(l)) | Both(BothOf { left: l, .. }) => Ok(l),
+        }
+    }
+}
+
+#[cfg(feature = "try_trait")]
+mod try_trait {
+    use crate::{AnyOf, Both, BothOf, Either, EitherOf, Left, Neither, Right};
+    use core::convert::Infallible;
+    use core::ops::{ControlFlow, FromResidual, Residual, Try};
+
+    impl<L, R> Try for EitherOf<L, R> {
+        type Output = R;
+        type Residual = EitherOf<L, Infallible>;
+
+        fn from_output(output: Self::Output) -> Self {
+            Right(output)
+        }
+
+        fn branch(self) -> ControlFlow<Self::Residual, Self::Output> {
+            match self {
+                Left(l) => ControlFlow::Break(Left(l)),
+                Right(r) => ControlFlow::Continue(r),
+            }
+        }
+    }
+
+    impl<L, L2: From<L>, R2> FromResidual<EitherOf<L, Infallible>> for EitherOf<L2, R2> {
+        fn from_residual(residual: EitherOf<L, Infallible>) -> Self {
+            match residual {
+                Left(l) => Left(l.into()),
+                Right(never) => match never {},
+            }
+        }
+    }
+
+    impl<L, R> Residual<R> for EitherOf<L, Infallible> {
+        type TryType = EitherOf<L, R>;
+    }
+
+    impl<L, R> Try for AnyOf<L, R> {
+        type Output = R;
+        type Residual = AnyOf<L, Infallible>;
+
+        fn from_output(output: Self::Output) -> Self {
+            Either(Right(output))
+        }
+
+        fn branch(self) -> ControlFlow<Self::Residual, Self::Output> {
+            match self {
+                Neither => ControlFlow::Break(Neither),
+                Either(Left(l)) => ControlFlow::Break(Either(Left(l))),
+                Either(Right(r)) | Both(BothOf { right: r, .. }) => ControlFlow::Continue(r),
+            }
+        }
+    }
+
+    impl<L, R> Residual<R> for AnyOf<L, Infallible> {
+        type TryType = AnyOf<L, R>;
+    }
+
+    impl<L, L2: From<L>, R2> FromResidual<AnyOf<L, Infallible>> for AnyOf<L2, R2> {
+        fn from_residual(residual: AnyOf<L, Infallible>) -> Self {
+            match residual {
+                Neither => Neither,
+                Either(Left(l)) => Either(Left(l.into())),
+                Either(Right(never)) | Both(BothOf { right: never, .. }) => match never {},
+            }
+        }
+    }
+
+    impl<L, L2: From<L>, R2> FromResidual<EitherOf<L, Infallible>> for AnyOf<L2, R2> {
+        fn from_residual(residual: EitherOf<L, Infallible>) -> Self {
+            Either(EitherOf::from_residual(residual))
+        }
+    }
+}
@@ -65,9 +65,13 @@
 //! ```
 //!
 
-use crate::concepts::{Map, Unwrap};
+use crate::concepts::{Bifoldable, Bitraversable, Map, Unwrap};
+use crate::parse::DisplayValue;
 use crate::{BothOf, LeftOrRight, Swap};
+use core::error::Error;
+use core::fmt::{Display, Formatter};
 use core::ops::{Not, Shr};
+use core::pin::Pin;
 
 /// The `EitherOf` enum is a utility type that can hold a value of one of two variants: `Left(L)` or `Right(R)`.
 ///
@@ -76,7 +80,18 @@ use core::ops::{Not, Shr};
 /// It serves as a straightforward alternative to `Result`, providing a way to perform operations
 /// on values of two possible types. Unlike `Result`, it does not imply any specific meaning
 /// to the variants.
-#[derive(Copy, Clone, Eq, PartialEq, Debug, Hash)]
+///
+/// # Ordering
+///
+/// `Left` values are lower than `Right` values, then the contents are compared.
+#[derive(Copy, Clone, Eq, PartialEq, Ord, PartialOrd, Debug, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "ufmt", derive(ufmt::derive::uDebug))]
+#[cfg_attr(
+    feature = "schemars",
+    derive(schemars::JsonSchema),
+    schemars(rename = "EitherOf_{L}_and_{R}", description = "Either a left or a right value.")
+)]
 pub enum EitherOf<L, R = L> {
     Left(L),
     Right(R),
@@ -108,6 +123,30 @@ impl<L, R> EitherOf<L, R> {
     pub fn new_right(right: R) -> Self {
         Self::Right(right)
     }
+
+    /// Converts from `Pin<&EitherOf<L, R>>` to `EitherOf<Pin<&L>, Pin<&R>>`.
+    pub fn as_pin_ref(self: Pin<&Self>) -> EitherOf<Pin<&L>, Pin<&R>> {
+        // SAFETY: the pinned value is only accessed through a pinned reference.
+        unsafe {
+            match self.get_ref() {
+                Self::Left(l) => EitherOf::Left(Pin::new_unchecked(l)),
+                Self::Right(r) => EitherOf::Right(Pin::new_unchecked(r)),
+            }
+        }
+    }
+
+    /// Converts from `Pin<&mut EitherOf<L, R>>` to `EitherOf<Pin<&mut L>, Pin<&mut R>>`.
+    ///
+    /// This is the pin-projection of `EitherOf`: the contained value is pinned too.
+    pub fn as_pin_mut(self: Pin<&mut Self>) -> EitherOf<Pin<&mut L>, Pin<&mut R>> {
+        // SAFETY: the contained value is never moved out of the pinned `EitherOf`.
+        unsafe {
+            match self.get_unchecked_mut() {
+                Self::Left(l) => EitherOf::Left(Pin::new_unchecked(l)),
+                Self::Right(r) => EitherOf::Right(Pin::new_unchecked(r)),
+            }
+        }
+    }
 }
 
 impl<L, R> LeftOrRight<L, R> for EitherOf<L, R> {
@@ -190,6 +229,43 @@ where
     }
 }
 
+impl<L, R> Bifoldable<L, R> for EitherOf<L, R> {
+    fn bifold<A, FL, FR>(self, init: A, fl: FL, fr: FR) -> A
+    where
+        FL: FnOnce(A, L) -> A,
+        FR: FnOnce(A, R) -> A,
+    {
+        match self {
+            Self::Left(l) => fl(init, l),
+            Self::Right(r) => fr(init, r),
+        }
+    }
+}
+
+impl<L, R> Bitraversable<L, R> for EitherOf<L, R> {
+    fn traverse_option<L2, R2, FL, FR>(self, fl: FL, fr: FR) -> Option<Self::Output<L2, R2>>
+    where
+        FL: FnOnce(L) -> Option<L2>,
+        FR: FnOnce(R) -> Option<R2>,
+    {
+        match self {
+            Self::Left(l) => fl(l).map(EitherOf::Left),
+            Self::Right(r) => fr(r).map(EitherOf::Right),
+        }
+    }
+
+    fn traverse_result<L2, R2, E, FL, FR>(self, fl: FL, fr: FR) -> Result<Self::Output<L2, R2>, E>
+    where
+        FL: FnOnce(L) -> Result<L2, E>,
+        FR: FnOnce(R) -> Result<R2, E>,
+    {
+        match self {
+            Self::Left(l) => fl(l).map(EitherOf::Left),
+            Self::Right(r) => fr(r).map(EitherOf::Right),
+        }
+    }
+}
+
 impl<L, R> Unwrap<L, R> for EitherOf<L, R> {
     fn left_or_else(self, f: impl FnOnce() -> L) -> L {
         match self {
@@ -205,3 +281,61 @@ impl<L, R> Unwrap<L, R> for EitherOf<L, R> {
         }
     }
 }
+
+impl<L: Display, R: Display> Display for EitherOf<L, R> {
+    /// Formats the value as `L(left)` or `R(right)`.
+    ///
+    /// A value which would not parse back as is is escaped (see the [parse](crate::parse)
+    /// module).
+    ///
+    /// # Examples
+    /// ```rust
+    /// use any_of::{EitherOf, Left, Right};
+    ///
+    /// let left: EitherOf<i32, &str> = Left(42);
+    /// let right: EitherOf<i32, &str> = Right("Hello");
+    /// assert_eq!(left.to_string(), "L(42)");
+    /// assert_eq!(right.to_string(), "R(Hello)");
+    /// ```
+    fn fmt(&self, f: &mut Formatter<'_>) -> core::fmt::Result {
+        match self {
+            Self::Left(l) => write!(f, "L({})", DisplayValue(l)),
+            Self::Right(r) => write!(f, "R({})", DisplayValue(r)),
+        }
+    }
+}
+
+#[cfg(feature = "defmt")]
+impl<L: defmt::Format, R: defmt::Format> defmt::Format for EitherOf<L, R> {
+    /// Formats the value as `L(left)` or `R(right)`, like `Display`.
+    fn format(&self, f: defmt::Formatter<'_>) {
+        match self {
+            Self::Left(l) => defmt::write!(f, "L({})", l),
+            Self::Right(r) => defmt::write!(f, "R({})", r),
+        }
+    }
+}
+
+#[cfg(feature = "ufmt")]
+impl<L: ufmt::uDisplay, R: ufmt::uDisplay> ufmt::uDisplay for EitherOf<L, R> {
+    /// Formats the value as `L(left)` or `R(right)`, like `Display`.
+    fn fmt<W>(&self, f: &mut ufmt::Formatter<'_, W>) -> Result<(), W::Error>
+    where
+        W: ufmt::uWrite + ?Sized,
+    {
+        match self {
+            Self::Left(l) => ufmt::uwrite!(f, "L({})", DisplayValue(l)),
+            Self::Right(r) => ufmt::uwrite!(f, "R({})", DisplayValue(r)),
+        }
+    }
+}
+
+impl<L: Error, R: Error> Error for EitherOf<L, R> {
+    /// Delegates to the `source()` of the contained error.
+    fn source(&self) -> Option<&(dyn Error + 'static)> {
+        match self {
+            Self::Left(l) => l.source(),
+            Self::Right(r) => r.source(),
+        }
+    }
+}
@@ -0,0 +1,146 @@
+//! This module provides an entry API for the sides of an [AnyOf], modelled on `HashMap::entry`.
+//!
+//! [AnyOf::entry_left] and [AnyOf::entry_right] return a [LeftEntry] or a [RightEntry],
+//! either `Occupied` or `Vacant`, giving in-place access to one side while the other
+//! side stays untouched:
+//!
+//! - `or_insert`, `or_insert_with` and `or_default` fill a vacant side,
+//! - `and_modify` updates an occupied side,
+//! - [OccupiedLeft::remove] and [OccupiedRight::remove] take the value out of its side.
+//!
+//! # Examples
+//!
+//! ```rust
+//! use any_of::AnyOf;
+//! use any_of::entry::RightEntry;
+//!
+//! let mut any: AnyOf<u32, u32> = AnyOf::new_neither();
+//! for digit in [1, 2, 3] {
+//!     any.entry_left().and_modify(|n| *n = *n * 10 + digit).or_insert(digit);
+//!     *any.entry_right().or_default() += 1;
+//! }
+//! assert_eq!(any, AnyOf::new_both(123, 3));
+//!
+//! if let RightEntry::Occupied(count) = any.entry_right() {
+//!     assert_eq!(count.remove(), 3);
+//! }
+//! assert_eq!(any, AnyOf::new_left(123));
+//! ```
+
+use crate::{AnyOf, LeftOrRight};
+
+macro_rules! side_entry {
+    (
+        $side:literal, $T:ident, $entry:ident, $occupied:ident, $vacant:ident;
+        $entry_fn:ident, $get:ident, $take:ident, $replace:ident, $present_mut:ident
+    ) => {
+        #[doc = concat!("A view into the ", $side, " side of an [AnyOf], returned by [AnyOf::", stringify!($entry_fn), "].")]
+        pub enum $entry<'a, L, R> {
+            #[doc = concat!("The ", $side, " value is present.")]
+            Occupied($occupied<'a, L, R>),
+            #[doc = concat!("The ", $side, " value is absent.")]
+            Vacant($vacant<'a, L, R>),
+        }
+
+        #[doc = concat!("A present ", $side, " value, see [", stringify!($entry), "].")]
+        pub struct $occupied<'a, L, R> {
+            any: &'a mut AnyOf<L, R>,
+        }
+
+        #[doc = concat!("An absent ", $side, " value, see [", stringify!($entry), "].")]
+        pub struct $vacant<'a, L, R> {
+            any: &'a mut AnyOf<L, R>,
+        }
+
+        impl<L, R> AnyOf<L, R> {
+            #[doc = concat!("Gets the entry of the ", $side, " side for in-place manipulation.")]
+            pub fn $entry_fn(&mut self) -> $entry<'_, L, R> {
+                if self.$get().is_some() {
+                    $entry::Occupied($occupied { any: self })
+                } else {
+                    $entry::Vacant($vacant { any: self })
+                }
+            }
+        }
+
+        impl<'a, L, R> $entry<'a, L, R> {
+            #[doc = concat!("Returns `true` if the ", $side, " value is present.")]
+            pub fn is_occupied(&self) -> bool {
+                matches!(self, $entry::Occupied(_))
+            }
+
+            /// Inserts `value` if vacant, and returns a mutable reference to the value.
+            pub fn or_insert(self, value: $T) -> &'a mut $T {
+                self.or_insert_with(|| value)
+            }
+
+            /// Inserts the result of `f` if vacant, and returns a mutable reference to the value.
+            pub fn or_insert_with(self, f: impl FnOnce() -> $T) -> &'a mut $T {
+                match self {
+                    $entry::Occupied(occupied) => occupied.into_mut(),
+                    $entry::Vacant(vacant) => vacant.insert(f()),
+                }
+            }
+
+            /// Inserts the default value if vacant, and returns a mutable reference to the value.
+            pub fn or_default(self) -> &'a mut $T
+            where
+                $T: Default,
+            {
+                self.or_insert_with(Default::default)
+            }
+
+            /// Calls `f` with the value if occupied, and returns the entry.
+            pub fn and_modify(mut self, f: impl FnOnce(&mut $T)) -> Self {
+                if let $entry::Occupied(occupied) = &mut self {
+                    f(occupied.get_mut());
+                }
+                self
+            }
+        }
+
+        impl<'a, L, R> $occupied<'a, L, R> {
+            #[doc = concat!("Returns a reference to the ", $side, " value.")]
+            pub fn get(&self) -> &$T {
+                self.any.$get().unwrap_or_else(|| unreachable!())
+            }
+
+            #[doc = concat!("Returns a mutable reference to the ", $side, " value.")]
+            pub fn get_mut(&mut self) -> &mut $T {
+                self.any.$present_mut()
+            }
+
+            #[doc = concat!("Converts into a mutable reference to the ", $side, " value, bound to the `AnyOf`.")]
+            pub fn into_mut(self) -> &'a mut $T {
+                self.any.$present_mut()
+            }
+
+            #[doc = concat!("Replaces the ", $side, " value, returning the old one.")]
+            pub fn insert(&mut self, value: $T) -> $T {
+                self.any.$replace(value).unwrap_or_else(|| unreachable!())
+            }
+
+            #[doc = concat!("Takes the ", $side, " value out, leaving the other side untouched.")]
+            pub fn remove(self) -> $T {
+                self.any.$take().unwrap_or_else(|| unreachable!())
+            }
+        }
+
+        impl<'a, L, R> $vacant<'a, L, R> {
+            #[doc = concat!("Sets the ", $side, " value, and returns a mutable reference to it.")]
+            pub fn insert(self, value: $T) -> &'a mut $T {
+                self.any.$replace(value);
+                self.any.$present_mut()
+            }
+        }
+    };
+}
+
+side_entry!(
+    "left", L, LeftEntry, OccupiedLeft, VacantLeft;
+    entry_left, left, take_left, replace_left, present_left_mut
+);
+side_entry!(
+    "right", R, RightEntry, OccupiedRight, VacantRight;
+    entry_right, right, take_right, replace_right, present_right_mut
+);
@@ -0,0 +1,73 @@
+//! This module provides [FfiEitherOf], a `#[repr(C, u8)]` counterpart of [EitherOf]
+//! with a guaranteed, FFI-safe layout.
+//!
+//! `EitherOf` keeps the default Rust representation, which lets the compiler optimize its size
+//! (e.g. with niches). `FfiEitherOf` is laid out as a C struct of a `u8` tag (`0` for `Left`,
+//! `1` for `Right`) followed by a C union of `L` and `R`, and converts from and into `EitherOf`.
+//!
+//! With the `bytemuck` feature, `FfiEitherOf<L, R>` is `Zeroable` when `L` is
+//! (an all-zero value is a zeroed `Left`).
+//!
+//! The `#[repr(C)]` layout of [BothOf](crate::BothOf) is documented on the type.
+//!
+//! # Examples
+//!
+//! ```rust
+//! use any_of::ffi::FfiEitherOf;
+//! use any_of::{EitherOf, Left};
+//!
+//! let ffi: FfiEitherOf<u8, u32> = Left(7).into();
+//! assert_eq!(ffi, FfiEitherOf::Left(7));
+//! assert_eq!(size_of::<FfiEitherOf<u8, u32>>(), 8);
+//! assert_eq!(EitherOf::from(ffi), Left(7));
+//! ```
+
+use crate::{EitherOf, Left, Right};
+
+/// A `#[repr(C, u8)]` [EitherOf]: a `u8` tag followed by a union of `L` and `R`.
+///
+/// See the [module documentation](self).
+#[derive(Copy, Clone, Eq, PartialEq, Ord, PartialOrd, Debug, Hash)]
+#[repr(C, u8)]
+pub enum FfiEitherOf<L, R = L> {
+    Left(L) = 0,
+    Right(R) = 1,
+}
+
+impl<L, R> FfiEitherOf<L, R> {
+    /// Converts into an [EitherOf].
+    pub fn into_either_of(self) -> EitherOf<L, R> {
+        match self {
+            FfiEitherOf::Left(l) => Left(l),
+            FfiEitherOf::Right(r) => Right(r),
+        }
+    }
+
+    /// Returns an [EitherOf] of references to the value.
+    pub fn as_either_of(&self) -> EitherOf<&L, &R> {
+        match self {
+            FfiEitherOf::Left(l) => Left(l),
+            FfiEitherOf::Right(r) => Right(r),
+        }
+    }
+}
+
+impl<L, R> From<EitherOf<L, R>> for FfiEitherOf<L, R> {
+    fn from(either: EitherOf<L, R>) -> Self {
+        match either {
+            Left(l) => FfiEitherOf::Left(l),
+            Right(r) => FfiEitherOf::Right(r),
+        }
+    }
+}
+
+impl<L, R> From<FfiEitherOf<L, R>> for EitherOf<L, R> {
+    fn from(ffi: FfiEitherOf<L, R>) -> Self {
+        ffi.into_either_of()
+    }
+}
+
+// SAFETY: `FfiEitherOf` is `repr(C, u8)`: an all-zero value has the tag `0` (`Left`)
+// and an all-zero `L`, which is valid as `L` is `Zeroable`.
+#[cfg(feature = "bytemuck")]
+unsafe impl<L: bytemuck::Zeroable, R> bytemuck::Zeroable for FfiEitherOf<L, R> {}
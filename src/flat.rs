@@ -0,0 +1,269 @@
+//! This module provides `FlatAnyOf8` and `FlatAnyOf16`, flat representations of [AnyOf8] and [AnyOf16].
+//!
+//! The nested `AnyOf8` and `AnyOf16` types walk up to four enum layers to reach a leaf,
+//! and each layer adds its own discriminant.
+//! The flat types store each leaf side by side as a `MaybeUninit`, and the presence of the leaves
+//! in a bitmask (a `u8` for `FlatAnyOf8`, a `u16` for `FlatAnyOf16`):
+//!
+//! - the leaf accessors (`lll()`, `rrrl()`, ...) and their mutable variants (`lll_mut()`, ...)
+//!   are O(1),
+//! - [FlatAnyOf8::shape] and [FlatAnyOf16::shape] return the raw bitmask.
+//!
+//! The bit `i` of the shape is set when the `i`-th leaf of the `Opt8` (or `Opt16`) tuple is present:
+//! `lll` is the bit `0` and `rrr` is the bit `7`.
+//!
+//! # Examples
+//!
+//! ```rust
+//! use any_of::{AnyOf, AnyOf4, AnyOf8, FlatAnyOf8};
+//!
+//! let nested: AnyOf8<i32> = AnyOf8::new_right(AnyOf4::new_left(AnyOf::new_both(1, 2)));
+//! let flat = FlatAnyOf8::from_nested(nested);
+//! assert_eq!(flat.shape(), 0b0011_0000);
+//! assert_eq!(flat.rll(), Some(&1));
+//! assert_eq!(flat.into_nested(), nested);
+//! ```
+
+use crate::concepts::{Opt8, Opt16};
+use crate::{AnyOf8, AnyOf16};
+use core::cmp::Ordering;
+use core::fmt::{Debug, Formatter};
+use core::hash::{Hash, Hasher};
+use core::mem::{ManuallyDrop, MaybeUninit};
+
+macro_rules! flat_any_of {
+    (
+        $(#[$meta:meta])*
+        $name:ident [$($decl:tt)*]: $mask:ty, $nested:ident, $opt:ident,
+        $from_opt:ident, $into_opt:ident, $as_opt:ident;
+        $($T:ident $leaf:ident $leaf_mut:ident $bit:literal),+ $(,)?
+    ) => {
+        $(#[$meta])*
+        pub struct $name<$($decl)*> {
+            $($leaf: MaybeUninit<$T>,)+
+            shape: $mask,
+        }
+
+        impl<$($T),+> $name<$($T),+> {
+            #[doc = concat!("Creates a `", stringify!($name), "` from an [", stringify!($opt), "] tuple.")]
+            #[allow(clippy::type_complexity)]
+            pub fn $from_opt(opt: $opt<$($T),+>) -> Self {
+                let ($($leaf,)+) = opt;
+                let mut shape = 0;
+                $(
+                    let $leaf = match $leaf {
+                        Some(value) => {
+                            shape |= 1 << $bit;
+                            MaybeUninit::new(value)
+                        }
+                        None => MaybeUninit::uninit(),
+                    };
+                )+
+                Self { $($leaf,)+ shape }
+            }
+
+            #[doc = concat!("Converts into an [", stringify!($opt), "] tuple.")]
+            #[allow(clippy::type_complexity)]
+            pub fn $into_opt(self) -> $opt<$($T),+> {
+                let this = ManuallyDrop::new(self);
+                // SAFETY: the leaves are read once according to the shape, and `this` is not dropped.
+                unsafe {
+                    ($(
+                        (this.shape & (1 << $bit) != 0).then(|| this.$leaf.assume_init_read()),
+                    )+)
+                }
+            }
+
+            #[doc = concat!("Returns the leaves as an [", stringify!($opt), "] tuple of references.")]
+            #[allow(clippy::type_complexity)]
+            pub fn $as_opt(&self) -> $opt<$(&$T),+> {
+                ($(self.$leaf(),)+)
+            }
+
+            #[doc = concat!("Creates a `", stringify!($name), "` from the nested [", stringify!($nested), "].")]
+            pub fn from_nested(nested: $nested<$($T),+>) -> Self {
+                Self::$from_opt(nested.$into_opt())
+            }
+
+            #[doc = concat!("Converts into the nested [", stringify!($nested), "].")]
+            pub fn into_nested(self) -> $nested<$($T),+> {
+                $nested::$from_opt(self.$into_opt())
+            }
+
+            #[doc = concat!("Returns the nested [", stringify!($nested), "] of references to the leaves.")]
+            pub fn as_nested(&self) -> $nested<$(&$T),+> {
+                $nested::$from_opt(self.$as_opt())
+            }
+
+            /// Returns the raw presence bitmask: the bit `i` is set when the `i`-th leaf is present.
+            pub fn shape(&self) -> $mask {
+                self.shape
+            }
+
+            /// True if no leaf is present.
+            pub fn is_empty(&self) -> bool {
+                self.shape == 0
+            }
+
+            /// Returns the number of present leaves.
+            pub fn presence_count(&self) -> usize {
+                self.shape.count_ones() as usize
+            }
+
+            $(
+                #[doc = concat!("Returns the `", stringify!($leaf), "` value if it exists.")]
+                pub fn $leaf(&self) -> Option<&$T> {
+                    // SAFETY: the leaf is initialized when its bit is set.
+                    (self.shape & (1 << $bit) != 0).then(|| unsafe { self.$leaf.assume_init_ref() })
+                }
+
+                #[doc = concat!("Returns a mutable reference to the `", stringify!($leaf), "` value if it exists.")]
+                pub fn $leaf_mut(&mut self) -> Option<&mut $T> {
+                    // SAFETY: the leaf is initialized when its bit is set.
+                    (self.shape & (1 << $bit) != 0).then(|| unsafe { self.$leaf.assume_init_mut() })
+                }
+            )+
+        }
+
+        impl<$($T),+> Drop for $name<$($T),+> {
+            fn drop(&mut self) {
+                // SAFETY: only the leaves marked as present by the shape are initialized.
+                unsafe {
+                    $(
+                        if self.shape & (1 << $bit) != 0 {
+                            self.$leaf.assume_init_drop();
+                        }
+                    )+
+                }
+            }
+        }
+
+        impl<$($T),+> From<$nested<$($T),+>> for $name<$($T),+> {
+            #[doc = concat!("See [", stringify!($name), "::from_nested].")]
+            fn from(value: $nested<$($T),+>) -> Self {
+                Self::from_nested(value)
+            }
+        }
+
+        impl<$($T),+> From<$name<$($T),+>> for $nested<$($T),+> {
+            #[doc = concat!("See [", stringify!($name), "::into_nested].")]
+            fn from(value: $name<$($T),+>) -> Self {
+                value.into_nested()
+            }
+        }
+
+        impl<$($T),+> From<$opt<$($T),+>> for $name<$($T),+> {
+            #[doc = concat!("See [", stringify!($name), "::", stringify!($from_opt), "].")]
+            fn from(value: $opt<$($T),+>) -> Self {
+                Self::$from_opt(value)
+            }
+        }
+
+        impl<$($T),+> From<$name<$($T),+>> for $opt<$($T),+> {
+            #[doc = concat!("See [", stringify!($name), "::", stringify!($into_opt), "].")]
+            fn from(value: $name<$($T),+>) -> Self {
+                value.$into_opt()
+            }
+        }
+
+        impl<$($T: Clone),+> Clone for $name<$($T),+> {
+            fn clone(&self) -> Self {
+                Self::$from_opt(($(self.$leaf().cloned(),)+))
+            }
+        }
+
+        impl<$($T: Debug),+> Debug for $name<$($T),+> {
+            /// Formats as the equivalent nested type.
+            fn fmt(&self, f: &mut Formatter<'_>) -> core::fmt::Result {
+                f.debug_tuple(stringify!($name))
+                    .field(&self.as_nested())
+                    .finish()
+            }
+        }
+
+        impl<$($T: PartialEq),+> PartialEq for $name<$($T),+> {
+            fn eq(&self, other: &Self) -> bool {
+                self.as_nested() == other.as_nested()
+            }
+        }
+
+        impl<$($T: Eq),+> Eq for $name<$($T),+> {}
+
+        impl<$($T: PartialOrd),+> PartialOrd for $name<$($T),+> {
+            /// Same order as the nested type.
+            fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+                self.as_nested().partial_cmp(&other.as_nested())
+            }
+        }
+
+        impl<$($T: Ord),+> Ord for $name<$($T),+> {
+            /// Same order as the nested type.
+            fn cmp(&self, other: &Self) -> Ordering {
+                self.as_nested().cmp(&other.as_nested())
+            }
+        }
+
+        impl<$($T: Hash),+> Hash for $name<$($T),+> {
+            fn hash<H: Hasher>(&self, state: &mut H) {
+                self.shape.hash(state);
+                $(self.$leaf().hash(state);)+
+            }
+        }
+    };
+}
+
+flat_any_of! {
+    /// A flat representation of [AnyOf8], with O(1) leaf access and a `u8` presence bitmask.
+    ///
+    /// See the [module documentation](self).
+    FlatAnyOf8 [LLL, LLR = LLL, LRL = LLR, LRR = LRL, RLL = LLL, RLR = LLR, RRL = LRL, RRR = LRR]:
+        u8, AnyOf8, Opt8, from_opt8, into_opt8, opt8;
+    LLL lll lll_mut 0,
+    LLR llr llr_mut 1,
+    LRL lrl lrl_mut 2,
+    LRR lrr lrr_mut 3,
+    RLL rll rll_mut 4,
+    RLR rlr rlr_mut 5,
+    RRL rrl rrl_mut 6,
+    RRR rrr rrr_mut 7,
+}
+
+flat_any_of! {
+    /// A flat representation of [AnyOf16], with O(1) leaf access and a `u16` presence bitmask.
+    ///
+    /// See the [module documentation](self).
+    FlatAnyOf16 [
+        LLLL,
+        LLLR = LLLL,
+        LLRL = LLLR,
+        LLRR = LLRL,
+        LRLL = LLLL,
+        LRLR = LLLR,
+        LRRL = LLRL,
+        LRRR = LLRR,
+        RLLL = LLLL,
+        RLLR = LLLR,
+        RLRL = LLRL,
+        RLRR = LLRR,
+        RRLL = LRLL,
+        RRLR = LRLR,
+        RRRL = LRRL,
+        RRRR = LRRR,
+    ]: u16, AnyOf16, Opt16, from_opt16, into_opt16, opt16;
+    LLLL llll llll_mut 0,
+    LLLR lllr lllr_mut 1,
+    LLRL llrl llrl_mut 2,
+    LLRR llrr llrr_mut 3,
+    LRLL lrll lrll_mut 4,
+    LRLR lrlr lrlr_mut 5,
+    LRRL lrrl lrrl_mut 6,
+    LRRR lrrr lrrr_mut 7,
+    RLLL rlll rlll_mut 8,
+    RLLR rllr rllr_mut 9,
+    RLRL rlrl rlrl_mut 10,
+    RLRR rlrr rlrr_mut 11,
+    RRLL rrll rrll_mut 12,
+    RRLR rrlr rrlr_mut 13,
+    RRRL rrrl rrrl_mut 14,
+    RRRR rrrr rrrr_mut 15,
+}
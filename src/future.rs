@@ -0,0 +1,316 @@
+//! This module provides the `Future` and `Stream` support of the `futures` feature.
+//!
+//! - [EitherOf] implements `Future` (and `Stream`) when both sides have the same output
+//!   (or item) type, polling the contained value.
+//! - [BothOf] implements `IntoFuture`: awaiting a `BothOf<F1, F2>` joins the two futures
+//!   into a `BothOf<F1::Output, F2::Output>`, see [Join].
+//! - [AnyOf] implements `IntoFuture`: awaiting an `AnyOf<F1, F2>` awaits the present futures
+//!   and resolves to an `AnyOf<F1::Output, F2::Output>` of the same shape, see [AnyOfFuture].
+//! - [race] polls two futures and resolves to the first output, or to both outputs when they
+//!   complete in the same poll, see [Race].
+//!
+//! # Examples
+//!
+//! ```rust
+//! use any_of::{AnyOf, BothOf, EitherOf, Left};
+//! use core::future::{ready, Ready};
+//!
+//! futures::executor::block_on(async {
+//!     let either: EitherOf<_, Ready<i32>> = Left(ready(1));
+//!     assert_eq!(either.await, 1);
+//!
+//!     let both = BothOf::new(ready(1), ready("one"));
+//!     assert_eq!(both.await, BothOf::new(1, "one"));
+//!
+//!     let any: AnyOf<_, Ready<&str>> = AnyOf::new_left(ready(1));
+//!     assert_eq!(any.await, AnyOf::new_left(1));
+//! });
+//! ```
+
+use crate::{AnyOf, Both, BothOf, Either, EitherOf, Left, Neither, Right};
+use core::future::{Future, IntoFuture};
+use core::pin::Pin;
+use core::task::{Context, Poll};
+use futures_core::Stream;
+
+impl<L, R> Future for EitherOf<L, R>
+where
+    L: Future,
+    R: Future<Output = L::Output>,
+{
+    type Output = L::Output;
+
+    fn poll(self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Self::Output> {
+        match self.as_pin_mut() {
+            Left(l) => l.poll(cx),
+            Right(r) => r.poll(cx),
+        }
+    }
+}
+
+impl<L, R> Stream for EitherOf<L, R>
+where
+    L: Stream,
+    R: Stream<Item = L::Item>,
+{
+    type Item = L::Item;
+
+    fn poll_next(self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Option<Self::Item>> {
+        match self.as_pin_mut() {
+            Left(l) => l.poll_next(cx),
+            Right(r) => r.poll_next(cx),
+        }
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        match self {
+            Left(l) => l.size_hint(),
+            Right(r) => r.size_hint(),
+        }
+    }
+}
+
+/// A future which is either still running, or has completed with an output not taken yet.
+enum MaybeDone<F: Future> {
+    Future(F),
+    Done(F::Output),
+    Gone,
+}
+
+impl<F: Future> MaybeDone<F> {
+    /// Polls the future if it is still running. Returns true if the output is available.
+    fn poll(self: Pin<&mut Self>, cx: &mut Context<'_>) -> bool {
+        // SAFETY: the future is never moved, it is dropped in place when it completes.
+        let this = unsafe { self.get_unchecked_mut() };
+        if let Self::Future(future) = this {
+            match unsafe { Pin::new_unchecked(future) }.poll(cx) {
+                Poll::Ready(output) => *this = Self::Done(output),
+                Poll::Pending => return false,
+            }
+        }
+        true
+    }
+
+    /// Takes the output out of a completed future.
+    ///
+    /// # Panics
+    ///
+    /// If the future is not completed or if the output has already been taken.
+    fn take(self: Pin<&mut Self>) -> F::Output {
+        // SAFETY: only the output is moved out, never the future.
+        let this = unsafe { self.get_unchecked_mut() };
+        match this {
+            Self::Done(_) => match core::mem::replace(this, Self::Gone) {
+                Self::Done(output) => output,
+                _ => unreachable!(),
+            },
+            _ => panic!("future polled after completion"),
+        }
+    }
+}
+
+/// The future of an awaited [BothOf]: it polls both futures and resolves to both outputs.
+///
+/// It is created with [BothOf::join] or with `IntoFuture`.
+#[must_use = "futures do nothing unless you `.await` or poll them"]
+pub struct Join<L: Future, R: Future> {
+    inner: BothOf<MaybeDone<L>, MaybeDone<R>>,
+}
+
+impl<L: Future, R: Future> Future for Join<L, R> {
+    type Output = BothOf<L::Output, R::Output>;
+
+    fn poll(self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Self::Output> {
+        // SAFETY: `inner` is structurally pinned.
+        let mut inner = unsafe { self.map_unchecked_mut(|join| &mut join.inner) };
+        let both = inner.as_mut().as_pin_mut();
+        let left = both.left.poll(cx);
+        let right = both.right.poll(cx);
+        if !(left && right) {
+            return Poll::Pending;
+        }
+        let both = inner.as_pin_mut();
+        Poll::Ready(BothOf::new(both.left.take(), both.right.take()))
+    }
+}
+
+impl<L: Future, R: Future> BothOf<L, R> {
+    /// Joins the two futures: see [Join].
+    pub fn join(self) -> Join<L, R> {
+        Join {
+            inner: BothOf::new(MaybeDone::Future(self.left), MaybeDone::Future(self.right)),
+        }
+    }
+}
+
+impl<L: Future, R: Future> IntoFuture for BothOf<L, R> {
+    type Output = BothOf<L::Output, R::Output>;
+    type IntoFuture = Join<L, R>;
+
+    fn into_future(self) -> Self::IntoFuture {
+        self.join()
+    }
+}
+
+/// The future of an awaited [AnyOf]: it polls the present futures and resolves
+/// to an [AnyOf] of their outputs, with the same shape.
+///
+/// It is created with [AnyOf::join] or with `IntoFuture`.
+#[must_use = "futures do nothing unless you `.await` or poll them"]
+pub struct AnyOfFuture<L: Future, R: Future> {
+    inner: AnyOf<MaybeDone<L>, MaybeDone<R>>,
+}
+
+impl<L: Future, R: Future> Future for AnyOfFuture<L, R> {
+    type Output = AnyOf<L::Output, R::Output>;
+
+    fn poll(self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Self::Output> {
+        // SAFETY: `inner` is structurally pinned.
+        let mut inner = unsafe { self.map_unchecked_mut(|future| &mut future.inner) };
+        let ready = match inner.as_mut().as_pin_mut() {
+            Neither => true,
+            Either(Left(l)) => l.poll(cx),
+            Either(Right(r)) => r.poll(cx),
+            Both(BothOf { left, right }) => {
+                let left = left.poll(cx);
+                let right = right.poll(cx);
+                left && right
+            }
+        };
+        if !ready {
+            return Poll::Pending;
+        }
+        Poll::Ready(match inner.as_pin_mut() {
+            Neither => Neither,
+            Either(Left(l)) => Either(Left(l.take())),
+            Either(Right(r)) => Either(Right(r.take())),
+            Both(BothOf { left, right }) => Both(BothOf::new(left.take(), right.take())),
+        })
+    }
+}
+
+impl<L: Future, R: Future> AnyOf<L, R> {
+    /// Awaits the present futures: see [AnyOfFuture].
+    pub fn join(self) -> AnyOfFuture<L, R> {
+        let inner = match self {
+            Neither => Neither,
+            Either(Left(l)) => Either(Left(MaybeDone::Future(l))),
+            Either(Right(r)) => Either(Right(MaybeDone::Future(r))),
+            Both(BothOf { left, right }) => {
+                Both(BothOf::new(MaybeDone::Future(left), MaybeDone::Future(right)))
+            }
+        };
+        AnyOfFuture { inner }
+    }
+}
+
+impl<L: Future, R: Future> IntoFuture for AnyOf<L, R> {
+    type Output = AnyOf<L::Output, R::Output>;
+    type IntoFuture = AnyOfFuture<L, R>;
+
+    fn into_future(self) -> Self::IntoFuture {
+        self.join()
+    }
+}
+
+/// The future returned by [race] and [BothOf::race].
+///
+/// It polls both futures and resolves to:
+///
+/// - `Either(Left(l))` if the left future completes first,
+/// - `Either(Right(r))` if the right future completes first,
+/// - `Both` if both futures complete during the same poll.
+///
+/// With [Race::extra_polls], the race waits a bounded number of extra polls for the other
+/// future once the first one has completed, and resolves to `Both` if it completes in time.
+/// The future that has not completed is dropped.
+#[must_use = "futures do nothing unless you `.await` or poll them"]
+pub struct Race<L: Future, R: Future> {
+    inner: BothOf<MaybeDone<L>, MaybeDone<R>>,
+    extra_polls: usize,
+}
+
+impl<L: Future, R: Future> Race<L, R> {
+    /// Sets the number of extra polls to wait for the other future once the first one
+    /// has completed.
+    ///
+    /// The race wakes itself up during these extra polls, so it resolves even if the other
+    /// future never wakes the task again.
+    ///
+    /// # Examples
+    /// ```rust
+    /// use any_of::future::race;
+    /// use any_of::AnyOf;
+    /// use core::future::{pending, ready};
+    ///
+    /// let both = race(ready(1), async {
+    ///     futures::pending!();
+    ///     "one"
+    /// });
+    /// assert_eq!(futures::executor::block_on(both.extra_polls(1)), AnyOf::new_both(1, "one"));
+    ///
+    /// let left = race(ready(1), pending::<&str>()).extra_polls(3);
+    /// assert_eq!(futures::executor::block_on(left), AnyOf::new_left(1));
+    /// ```
+    pub fn extra_polls(mut self, polls: usize) -> Self {
+        self.extra_polls = polls;
+        self
+    }
+}
+
+impl<L: Future, R: Future> Future for Race<L, R> {
+    type Output = AnyOf<L::Output, R::Output>;
+
+    fn poll(self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Self::Output> {
+        // SAFETY: `inner` is structurally pinned, `extra_polls` is not.
+        let this = unsafe { self.get_unchecked_mut() };
+        let both = unsafe { Pin::new_unchecked(&mut this.inner) }.as_pin_mut();
+        let left = both.left.poll(cx);
+        let right = both.right.poll(cx);
+        if !(left || right) {
+            return Poll::Pending;
+        }
+        if left != right && this.extra_polls > 0 {
+            this.extra_polls -= 1;
+            cx.waker().wake_by_ref();
+            return Poll::Pending;
+        }
+        let both = unsafe { Pin::new_unchecked(&mut this.inner) }.as_pin_mut();
+        Poll::Ready(match (left, right) {
+            (true, false) => Either(Left(both.left.take())),
+            (false, true) => Either(Right(both.right.take())),
+            _ => Both(BothOf::new(both.left.take(), both.right.take())),
+        })
+    }
+}
+
+/// Races two futures: see [Race].
+///
+/// When both futures are ready during the same poll, the result is `Both`,
+/// which `select`-like combinators cannot express.
+///
+/// # Examples
+/// ```rust
+/// use any_of::future::race;
+/// use any_of::AnyOf;
+/// use core::future::{pending, ready};
+///
+/// futures::executor::block_on(async {
+///     assert_eq!(race(ready(1), pending::<&str>()).await, AnyOf::new_left(1));
+///     assert_eq!(race(pending::<i32>(), ready("one")).await, AnyOf::new_right("one"));
+///     assert_eq!(race(ready(1), ready("one")).await, AnyOf::new_both(1, "one"));
+/// });
+/// ```
+pub fn race<L: Future, R: Future>(left: L, right: R) -> Race<L, R> {
+    Race {
+        inner: BothOf::new(MaybeDone::Future(left), MaybeDone::Future(right)),
+        extra_polls: 0,
+    }
+}
+
+impl<L: Future, R: Future> BothOf<L, R> {
+    /// Races the two futures: see [race].
+    pub fn race(self) -> Race<L, R> {
+        race(self.left, self.right)
+    }
+}
@@ -0,0 +1,131 @@
+//! This module provides the outer join of two sorted slices, as an iterator of [AnyOf].
+//!
+//! Both slices must be sorted by their keys. The join merges them in key order and yields:
+//!
+//! - `Left(l)` for a left element without a matching right element,
+//! - `Right(r)` for a right element without a matching left element,
+//! - `Both(l, r)` for two elements with the same key.
+//!
+//! Elements with duplicate keys are paired in order, like in a merge.
+//!
+//! # Examples
+//!
+//! ```rust
+//! use any_of::join::outer_join;
+//! use any_of::AnyOf;
+//!
+//! let joined: Vec<_> = outer_join(&[1, 2, 4], &[2, 3, 4]).collect();
+//! assert_eq!(
+//!     joined,
+//!     vec![
+//!         AnyOf::new_left(&1),
+//!         AnyOf::new_both(&2, &2),
+//!         AnyOf::new_right(&3),
+//!         AnyOf::new_both(&4, &4),
+//!     ]
+//! );
+//! ```
+
+use crate::AnyOf;
+use core::cmp::Ordering;
+use core::iter::FusedIterator;
+
+/// The iterator returned by [outer_join] and [outer_join_by_key].
+#[must_use = "iterators are lazy and do nothing unless consumed"]
+pub struct OuterJoin<'a, L, R, FL, FR> {
+    left: &'a [L],
+    right: &'a [R],
+    left_key: FL,
+    right_key: FR,
+}
+
+impl<'a, L, R, K, FL, FR> Iterator for OuterJoin<'a, L, R, FL, FR>
+where
+    K: Ord,
+    FL: FnMut(&'a L) -> K,
+    FR: FnMut(&'a R) -> K,
+{
+    type Item = AnyOf<&'a L, &'a R>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        let ordering = match (self.left.first(), self.right.first()) {
+            (None, None) => return None,
+            (Some(_), None) => Ordering::Less,
+            (None, Some(_)) => Ordering::Greater,
+            (Some(l), Some(r)) => (self.left_key)(l).cmp(&(self.right_key)(r)),
+        };
+        let (left, right) = match ordering {
+            Ordering::Less => (self.left.split_first(), None),
+            Ordering::Greater => (None, self.right.split_first()),
+            Ordering::Equal => (self.left.split_first(), self.right.split_first()),
+        };
+        let left = left.map(|(l, rest)| {
+            self.left = rest;
+            l
+        });
+        let right = right.map(|(r, rest)| {
+            self.right = rest;
+            r
+        });
+        Some(AnyOf::new(left, right))
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        let (left, right) = (self.left.len(), self.right.len());
+        (left.max(right), Some(left + right))
+    }
+}
+
+impl<'a, L, R, K, FL, FR> FusedIterator for OuterJoin<'a, L, R, FL, FR>
+where
+    K: Ord,
+    FL: FnMut(&'a L) -> K,
+    FR: FnMut(&'a R) -> K,
+{
+}
+
+/// Joins two slices sorted by the keys returned by `left_key` and `right_key`.
+///
+/// See the [module documentation](self).
+///
+/// # Examples
+/// ```rust
+/// use any_of::join::outer_join_by_key;
+/// use any_of::AnyOf;
+///
+/// let users = [(1, "Alice"), (2, "Bob")];
+/// let orders = [(2, 30.0), (3, 12.5)];
+/// let joined: Vec<_> = outer_join_by_key(&users, &orders, |u| u.0, |o| o.0).collect();
+/// assert_eq!(joined[0], AnyOf::new_left(&(1, "Alice")));
+/// assert_eq!(joined[1], AnyOf::new_both(&(2, "Bob"), &(2, 30.0)));
+/// assert_eq!(joined[2], AnyOf::new_right(&(3, 12.5)));
+/// ```
+pub fn outer_join_by_key<'a, L, R, K, FL, FR>(
+    left: &'a [L],
+    right: &'a [R],
+    left_key: FL,
+    right_key: FR,
+) -> OuterJoin<'a, L, R, FL, FR>
+where
+    K: Ord,
+    FL: FnMut(&'a L) -> K,
+    FR: FnMut(&'a R) -> K,
+{
+    OuterJoin {
+        left,
+        right,
+        left_key,
+        right_key,
+    }
+}
+
+/// Joins two sorted slices of the same type.
+///
+/// See [outer_join_by_key].
+#[allow(clippy::type_complexity)]
+pub fn outer_join<'a, T: Ord>(
+    left: &'a [T],
+    right: &'a [T],
+) -> OuterJoin<'a, T, T, fn(&'a T) -> &'a T, fn(&'a T) -> &'a T> {
+    outer_join_by_key(left, right, |l| l, |r| r)
+}
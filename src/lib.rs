@@ -52,12 +52,40 @@
 //! - [AnyOf::unwrap_right] and [AnyOf::right]
 //! - [AnyOf::unwrap_both] and [AnyOf::both_or_none]
 //!
+//! ## Modules :
+//! - [validate] : accumulation of diagnostics with `AnyOf<E, T>`.
+//! - [early_return] : `?`-like early returns ([right_or_return], [left_or_return]) and conversions to `Result`.
+//! - `future` (`futures` feature) : `Future`, `IntoFuture` and `Stream` implementations.
+//! - [ordering] : comparators for other ordering policies.
+//! - [transpose] : `transpose` of `Option` and `Result` sides, and [AnyOf::flatten].
+//! - [zip] : `zip`, `zip_with` and `unzip` for `AnyOf` and `BothOf`.
+//! - [algebra] : associativity, distributivity and balancing of nested types.
+//! - [entry] : entry API for the sides of an `AnyOf`.
+//! - [parse] : `FromStr` for the `Display` format (`_`, `L(…)`, `R(…)`, `B(…, …)`).
+//! - [codec] : compact binary encoding with a shape header ([codec::Encode], [codec::Decode]).
+//! - [packed] : [PackedAnyOf], a layout-optimized `AnyOf`.
+//! - [flat] : [FlatAnyOf8] and [FlatAnyOf16], bitmask-backed `AnyOf8` and `AnyOf16`.
+//! - [builder] : named-leaf builders for `AnyOf8` and `AnyOf16`, and [leaf_aliases] for domain names.
+//! - [ffi] : [ffi::FfiEitherOf], a `#[repr(C, u8)]` `EitherOf` with an FFI-safe layout.
+//! - `any_of_vec` (`alloc` feature) : `AnyOfVec`, a struct-of-arrays collection of `AnyOf`.
+//! - [join] : outer join of sorted slices.
+//! - `parallel` (`rayon` feature) : `rayon` parallel iterators, partitions and outer join.
+//! - `tristate` (`serde` feature) : `Tristate`, a missing, null or present field.
+//!
 //! ## Exported elements :
 //! - Enum cases : [Left], [Right], [Both], [Either], [Neither],
-//! - Traits : [LeftOrRight], [Unwrap], [Map], [Swap],
-//! - Types : [Couple], [Pair], [EitherOf], [BothOf], [AnyOf], [AnyOf4], [AnyOf8], [AnyOf16]
+//! - Traits : [LeftOrRight], [Unwrap], [Map], [Swap], [Bifoldable], [Bitraversable], [Semigroup],
+//! - Macros : [match_any], [any_of], [any_of_both], [any_of_left], [any_of_right], [leaf_aliases], [right_or_return], [left_or_return],
+//! - Types : [Couple], [Pair], [EitherOf], [BothOf], [AnyOf], [AnyOf4], [AnyOf8], [AnyOf16], [PackedAnyOf], [FlatAnyOf8], [FlatAnyOf16]
 //!
 #![no_std]
+#![cfg_attr(feature = "try_trait", feature(try_trait_v2, try_trait_v2_residual))]
+
+#[cfg(feature = "alloc")]
+extern crate alloc;
+
+#[cfg(test)]
+extern crate std;
 
 pub mod concepts;
 
@@ -65,14 +93,62 @@ pub mod either;
 
 pub mod both;
 
+pub mod validate;
+
+pub mod early_return;
+
+pub mod ordering;
+
+pub mod transpose;
+
+pub mod zip;
+
+pub mod algebra;
+
+pub mod entry;
+
+pub mod parse;
+
+pub mod codec;
+
+pub mod packed;
+
+pub mod flat;
+
+pub mod builder;
+
+pub mod ffi;
+
+#[cfg(feature = "alloc")]
+pub mod any_of_vec;
+
+pub mod join;
+
+#[cfg(feature = "rayon")]
+pub mod parallel;
+
+#[cfg(feature = "futures")]
+pub mod future;
+
+#[cfg(feature = "serde")]
+pub mod tristate;
+
+use core::error::Error;
+use core::fmt::{Display, Formatter};
 use core::ops::{Add, Not, Shr, Sub};
+use core::pin::Pin;
 
 pub use crate::{
     any_of_x::{AnyOf16, AnyOf4, AnyOf8},
     both::BothOf,
-    concepts::{Couple, LeftOrRight, Map, Opt16, Opt2, Opt4, Opt8, Pair, Swap, Unwrap},
+    concepts::{
+        Bifoldable, Bitraversable, Couple, LeftOrRight, Map, Opt16, Opt2, Opt4, Opt8, Pair, Semigroup,
+        Swap, Unwrap,
+    },
     either::EitherOf,
     either::EitherOf::{Left, Right},
+    flat::{FlatAnyOf16, FlatAnyOf8},
+    packed::PackedAnyOf,
     AnyOf::{Both, Either, Neither},
 };
 
@@ -114,7 +190,20 @@ pub use crate::{
 /// assert!(both.map_left(|l| l).is_both());
 ///
 /// ```
-#[derive(Copy, Clone, Eq, PartialEq, Debug, Hash)]
+///
+/// # Ordering
+///
+/// The variants are ordered `Neither < Left < Right < Both`, then the contents are compared.
+/// The same order applies to [AnyOf4], [AnyOf8] and [AnyOf16].
+/// Other policies are provided by the [ordering] module.
+#[derive(Copy, Clone, Eq, PartialEq, Ord, PartialOrd, Debug, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "ufmt", derive(ufmt::derive::uDebug))]
+#[cfg_attr(
+    feature = "schemars",
+    derive(schemars::JsonSchema),
+    schemars(rename = "AnyOf_{L}_and_{R}", description = "Neither, either a left or a right value, or both values.")
+)]
 pub enum AnyOf<L, R = L> {
     Neither,
     Either(EitherOf<L, R>),
@@ -240,6 +329,25 @@ impl<L, R> AnyOf<L, R> {
         Self::new(any.0, any.1)
     }
 
+    /// Converts into a pair of options, without cloning the values.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use any_of::AnyOf;
+    ///
+    /// let both: AnyOf<i32, &str> = AnyOf::new_both(42, "Hello");
+    /// assert_eq!(both.into_opt2(), (Some(42), Some("Hello")));
+    /// ```
+    pub fn into_opt2(self) -> Opt2<L, R> {
+        match self {
+            Neither => (None, None),
+            Either(Left(l)) => (Some(l), None),
+            Either(Right(r)) => (None, Some(r)),
+            Both(BothOf { left, right }) => (Some(left), Some(right)),
+        }
+    }
+
     /// Creates an `AnyOf` variant from an `Either` struct.
     ///
     /// # Examples
@@ -430,6 +538,111 @@ impl<L, R> AnyOf<L, R> {
         }
     }
 
+    /// Takes the value out, leaving `Neither` in its place.
+    pub fn take(&mut self) -> Self {
+        core::mem::replace(self, Neither)
+    }
+
+    /// Takes the left value out if present, leaving the right value (if any) in place.
+    ///
+    /// # Examples
+    /// ```rust
+    /// use any_of::AnyOf;
+    ///
+    /// let mut any: AnyOf<i32, &str> = AnyOf::new_both(1, "one");
+    /// assert_eq!(any.take_left(), Some(1));
+    /// assert_eq!(any, AnyOf::new_right("one"));
+    /// assert_eq!(any.take_left(), None);
+    /// ```
+    pub fn take_left(&mut self) -> Option<L> {
+        let (left, right) = self.take().into_opt2();
+        *self = AnyOf::new(None, right);
+        left
+    }
+
+    /// Takes the right value out if present, leaving the left value (if any) in place.
+    pub fn take_right(&mut self) -> Option<R> {
+        let (left, right) = self.take().into_opt2();
+        *self = AnyOf::new(left, None);
+        right
+    }
+
+    /// Sets the left value, returning the old one if present.
+    ///
+    /// # Examples
+    /// ```rust
+    /// use any_of::AnyOf;
+    ///
+    /// let mut any: AnyOf<i32, &str> = AnyOf::new_right("one");
+    /// assert_eq!(any.replace_left(1), None);
+    /// assert_eq!(any.replace_left(2), Some(1));
+    /// assert_eq!(any, AnyOf::new_both(2, "one"));
+    /// ```
+    pub fn replace_left(&mut self, left: L) -> Option<L> {
+        let (old, right) = self.take().into_opt2();
+        *self = AnyOf::new(Some(left), right);
+        old
+    }
+
+    /// Sets the right value, returning the old one if present.
+    pub fn replace_right(&mut self, right: R) -> Option<R> {
+        let (left, old) = self.take().into_opt2();
+        *self = AnyOf::new(left, Some(right));
+        old
+    }
+
+    /// Sets the left value, dropping the old one if present, and returns a mutable reference to it.
+    pub fn insert_left(&mut self, left: L) -> &mut L {
+        self.replace_left(left);
+        self.present_left_mut()
+    }
+
+    /// Sets the right value, dropping the old one if present, and returns a mutable reference to it.
+    pub fn insert_right(&mut self, right: R) -> &mut R {
+        self.replace_right(right);
+        self.present_right_mut()
+    }
+
+    /// Sets the left value with `f` if absent, and returns a mutable reference to it.
+    ///
+    /// # Examples
+    /// ```rust
+    /// use any_of::AnyOf;
+    ///
+    /// let mut any: AnyOf<i32, &str> = AnyOf::new_neither();
+    /// *any.get_or_insert_left_with(|| 1) += 1;
+    /// assert_eq!(*any.get_or_insert_left_with(|| 10), 2);
+    /// assert_eq!(any, AnyOf::new_left(2));
+    /// ```
+    pub fn get_or_insert_left_with(&mut self, f: impl FnOnce() -> L) -> &mut L {
+        if !self.has_left() {
+            self.replace_left(f());
+        }
+        self.present_left_mut()
+    }
+
+    /// Sets the right value with `f` if absent, and returns a mutable reference to it.
+    pub fn get_or_insert_right_with(&mut self, f: impl FnOnce() -> R) -> &mut R {
+        if !self.has_right() {
+            self.replace_right(f());
+        }
+        self.present_right_mut()
+    }
+
+    fn present_left_mut(&mut self) -> &mut L {
+        match self {
+            Either(Left(left)) | Both(BothOf { left, .. }) => left,
+            _ => unreachable!("the left value is present"),
+        }
+    }
+
+    fn present_right_mut(&mut self) -> &mut R {
+        match self {
+            Either(Right(right)) | Both(BothOf { right, .. }) => right,
+            _ => unreachable!("the right value is present"),
+        }
+    }
+
     /// Combines (`+` operator) two `Either` values into a single one.
     ///
     /// ## General rules
@@ -478,6 +691,32 @@ impl<L, R> AnyOf<L, R> {
         }
     }
 
+    /// Converts from `Pin<&AnyOf<L, R>>` to `AnyOf<Pin<&L>, Pin<&R>>`.
+    pub fn as_pin_ref(self: Pin<&Self>) -> AnyOf<Pin<&L>, Pin<&R>> {
+        // SAFETY: the pinned values are only accessed through pinned references.
+        unsafe {
+            match self.get_ref() {
+                Neither => Neither,
+                Either(e) => Either(Pin::new_unchecked(e).as_pin_ref()),
+                Both(b) => Both(Pin::new_unchecked(b).as_pin_ref()),
+            }
+        }
+    }
+
+    /// Converts from `Pin<&mut AnyOf<L, R>>` to `AnyOf<Pin<&mut L>, Pin<&mut R>>`.
+    ///
+    /// This is the pin-projection of `AnyOf`: the contained values are pinned too.
+    pub fn as_pin_mut(self: Pin<&mut Self>) -> AnyOf<Pin<&mut L>, Pin<&mut R>> {
+        // SAFETY: the contained values are never moved out of the pinned `AnyOf`.
+        unsafe {
+            match self.get_unchecked_mut() {
+                Neither => Neither,
+                Either(e) => Either(Pin::new_unchecked(e).as_pin_mut()),
+                Both(b) => Both(Pin::new_unchecked(b).as_pin_mut()),
+            }
+        }
+    }
+
     /// Filters (`-` operator) the current `AnyOf` instance using another `AnyOf` instance.
     ///
     /// ## General rules
@@ -624,6 +863,46 @@ impl<L, R> Map<L, R> for AnyOf<L, R> {
     }
 }
 
+impl<L, R> Bifoldable<L, R> for AnyOf<L, R> {
+    fn bifold<A, FL, FR>(self, init: A, fl: FL, fr: FR) -> A
+    where
+        FL: FnOnce(A, L) -> A,
+        FR: FnOnce(A, R) -> A,
+    {
+        match self {
+            Neither => init,
+            Either(either) => either.bifold(init, fl, fr),
+            Both(both) => both.bifold(init, fl, fr),
+        }
+    }
+}
+
+impl<L, R> Bitraversable<L, R> for AnyOf<L, R> {
+    fn traverse_option<L2, R2, FL, FR>(self, fl: FL, fr: FR) -> Option<Self::Output<L2, R2>>
+    where
+        FL: FnOnce(L) -> Option<L2>,
+        FR: FnOnce(R) -> Option<R2>,
+    {
+        Some(match self {
+            Neither => Neither,
+            Either(either) => Either(either.traverse_option(fl, fr)?),
+            Both(both) => Both(both.traverse_option(fl, fr)?),
+        })
+    }
+
+    fn traverse_result<L2, R2, E, FL, FR>(self, fl: FL, fr: FR) -> Result<Self::Output<L2, R2>, E>
+    where
+        FL: FnOnce(L) -> Result<L2, E>,
+        FR: FnOnce(R) -> Result<R2, E>,
+    {
+        Ok(match self {
+            Neither => Neither,
+            Either(either) => Either(either.traverse_result(fl, fr)?),
+            Both(both) => Both(both.traverse_result(fl, fr)?),
+        })
+    }
+}
+
 impl<L, R> Unwrap<L, R> for AnyOf<L, R> {
     /// Returns the left value if present, or computes it with the provided function.
     fn left_or_else(self, f: impl FnOnce() -> L) -> L {
@@ -681,9 +960,74 @@ where
     }
 }
 
+impl<L: Display, R: Display> Display for AnyOf<L, R> {
+    /// Formats the value as `_`, `L(left)`, `R(right)` or `B(left, right)`.
+    ///
+    /// The format of `Either` and `Both` is the one of [EitherOf] and [BothOf].
+    ///
+    /// # Examples
+    /// ```rust
+    /// use any_of::AnyOf;
+    ///
+    /// assert_eq!(AnyOf::<i32, &str>::new_neither().to_string(), "_");
+    /// assert_eq!(AnyOf::<i32, &str>::new_left(42).to_string(), "L(42)");
+    /// assert_eq!(AnyOf::<i32, &str>::new_right("Hello").to_string(), "R(Hello)");
+    /// assert_eq!(AnyOf::new_both(42, "Hello").to_string(), "B(42, Hello)");
+    /// ```
+    fn fmt(&self, f: &mut Formatter<'_>) -> core::fmt::Result {
+        match self {
+            Neither => f.write_str("_"),
+            Either(e) => e.fmt(f),
+            Both(b) => b.fmt(f),
+        }
+    }
+}
+
+#[cfg(feature = "defmt")]
+impl<L: defmt::Format, R: defmt::Format> defmt::Format for AnyOf<L, R> {
+    /// Formats the value as `_`, `L(left)`, `R(right)` or `B(left, right)`, like `Display`.
+    ///
+    /// The nested [AnyOf4], [AnyOf8] and [AnyOf16] are formatted recursively.
+    fn format(&self, f: defmt::Formatter<'_>) {
+        match self {
+            Neither => defmt::write!(f, "_"),
+            Either(e) => e.format(f),
+            Both(b) => b.format(f),
+        }
+    }
+}
+
+#[cfg(feature = "ufmt")]
+impl<L: ufmt::uDisplay, R: ufmt::uDisplay> ufmt::uDisplay for AnyOf<L, R> {
+    /// Formats the value as `_`, `L(left)`, `R(right)` or `B(left, right)`, like `Display`.
+    fn fmt<W>(&self, f: &mut ufmt::Formatter<'_, W>) -> Result<(), W::Error>
+    where
+        W: ufmt::uWrite + ?Sized,
+    {
+        match self {
+            Neither => f.write_str("_"),
+            Either(e) => ufmt::uDisplay::fmt(e, f),
+            Both(b) => ufmt::uDisplay::fmt(b, f),
+        }
+    }
+}
+
+impl<L: Error, R: Error> Error for AnyOf<L, R> {
+    /// Delegates to [EitherOf] or [BothOf]. `Neither` has no source.
+    fn source(&self) -> Option<&(dyn Error + 'static)> {
+        match self {
+            Neither => None,
+            Either(e) => e.source(),
+            Both(b) => b.source(),
+        }
+    }
+}
+
 mod conversions;
 
 mod any_of_x;
 
+mod macros;
+
 #[cfg(test)]
 mod tests;
@@ -0,0 +1,242 @@
+//! This module provides the macros of the crate, exported at the crate root.
+
+/// Matches an [AnyOf](crate::AnyOf) (or an [AnyOf4](crate::AnyOf4), [AnyOf8](crate::AnyOf8),
+/// [AnyOf16](crate::AnyOf16)) on its presence shape.
+///
+/// The arms are written `shape => expression,` (with an optional `if` guard), where `shape` is:
+///
+/// - for an `AnyOf`: `()` (`Neither`), `(l, _)` (`Left`), `(_, r)` (`Right`) or `(l, r)` (`Both`):
+///   `_` marks an absent side, and the other patterns are matched against the present values,
+/// - for any `AnyOf` (nested or not): `{ slot: pattern, ... }`, naming the present leaves only.
+///   A slot is named by its path from the root: `l` and `r` for an `AnyOf`, `ll` to `rr` for
+///   an `AnyOf4`, `lll` to `rrr` for an `AnyOf8` and `llll` to `rrrr` for an `AnyOf16`.
+///   `{}` matches `Neither`.
+///
+/// Each shape expands to the nested native pattern, so the match is checked for exhaustiveness
+/// by the compiler.
+///
+/// # Examples
+/// ```rust
+/// use any_of::{match_any, AnyOf, AnyOf4};
+///
+/// fn describe(value: AnyOf<i32, &str>) -> String {
+///     match_any!(value, {
+///         () => "nothing".to_string(),
+///         (l, _) if l < 0 => format!("negative {l}"),
+///         (l, _) => format!("left {l}"),
+///         (_, r) => format!("right {r}"),
+///         (l, r) => format!("{l} and {r}"),
+///     })
+/// }
+/// assert_eq!(describe(AnyOf::new_both(1, "one")), "1 and one");
+/// assert_eq!(describe(AnyOf::new_left(-1)), "negative -1");
+///
+/// let any4: AnyOf4<i32> = AnyOf4::new4(Some(1), None, None, Some(4));
+/// let sum = match_any!(any4, {
+///     { ll: a, rr: d } => a + d,
+///     {} => 0,
+///     _ => -1,
+/// });
+/// assert_eq!(sum, 5);
+/// ```
+///
+/// A missing shape does not compile:
+/// ```rust,compile_fail
+/// use any_of::{match_any, AnyOf};
+///
+/// let value: AnyOf<i32> = AnyOf::new_left(1);
+/// match_any!(value, {
+///     () => 0,
+///     (l, _) => l,
+///     (_, r) => r,
+/// });
+/// ```
+#[macro_export]
+macro_rules! match_any {
+    ($value:expr, { $($shape:tt $(if $guard:expr)? => $body:expr),* $(,)? }) => {
+        match $value {
+            $($crate::match_any!(@shape $shape) $(if $guard)? => $body,)*
+        }
+    };
+
+    // Shapes.
+    (@shape _) => { _ };
+    (@shape ()) => { $crate::AnyOf::Neither };
+    (@shape {}) => { $crate::AnyOf::Neither };
+    (@shape (_, _)) => { $crate::AnyOf::Neither };
+    (@shape (_, $r:pat)) => { $crate::match_any!(@any_of [] [([] ($r))]) };
+    (@shape ($l:pat, _)) => { $crate::match_any!(@any_of [([] ($l))] []) };
+    (@shape ($l:pat, $r:pat)) => { $crate::match_any!(@any_of [([] ($l))] [([] ($r))]) };
+    (@shape { $($fields:tt)* }) => { $crate::match_any!(@fields [] $($fields)*) };
+
+    // Named slots: each `slot: pattern` becomes a `([path] (pattern))` entry.
+    (@fields [$($entries:tt)*] $(,)?) => { $crate::match_any!(@split [] [] $($entries)*) };
+    (@fields [$($entries:tt)*] $slot:ident : $p:pat $(, $($rest:tt)*)?) => {
+        $crate::match_any!(@path $slot (@field [$($entries)*] ($p) [$($($rest)*)?]))
+    };
+    (@field [$($entries:tt)*] $p:tt [$($rest:tt)*] $path:tt) => {
+        $crate::match_any!(@fields [$($entries)* ($path $p)] $($rest)*)
+    };
+
+    // Splits the entries by their first side, and builds the pattern of the current level.
+    (@split [$($l:tt)*] [$($r:tt)*]) => { $crate::match_any!(@any_of [$($l)*] [$($r)*]) };
+    (@split [$($l:tt)*] [$($r:tt)*] ([l $($path:tt)*] $p:tt) $($rest:tt)*) => {
+        $crate::match_any!(@split [$($l)* ([$($path)*] $p)] [$($r)*] $($rest)*)
+    };
+    (@split [$($l:tt)*] [$($r:tt)*] ([r $($path:tt)*] $p:tt) $($rest:tt)*) => {
+        $crate::match_any!(@split [$($l)*] [$($r)* ([$($path)*] $p)] $($rest)*)
+    };
+    (@any_of [] []) => { $crate::AnyOf::Neither };
+    (@any_of [$($l:tt)+] []) => {
+        $crate::AnyOf::Either($crate::EitherOf::Left($crate::match_any!(@node $($l)+)))
+    };
+    (@any_of [] [$($r:tt)+]) => {
+        $crate::AnyOf::Either($crate::EitherOf::Right($crate::match_any!(@node $($r)+)))
+    };
+    (@any_of [$($l:tt)+] [$($r:tt)+]) => {
+        $crate::AnyOf::Both($crate::BothOf {
+            left: $crate::match_any!(@node $($l)+),
+            right: $crate::match_any!(@node $($r)+),
+        })
+    };
+    (@node ([] ($($p:tt)*))) => { $($p)* };
+    (@node $($entries:tt)+) => { $crate::match_any!(@split [] [] $($entries)+) };
+
+    // Slot names, passing their path to the continuation `$k`.
+    (@path l ($($k:tt)*)) => { $crate::match_any!($($k)* [l]) };
+    (@path r ($($k:tt)*)) => { $crate::match_any!($($k)* [r]) };
+    (@path ll ($($k:tt)*)) => { $crate::match_any!($($k)* [l l]) };
+    (@path lr ($($k:tt)*)) => { $crate::match_any!($($k)* [l r]) };
+    (@path rl ($($k:tt)*)) => { $crate::match_any!($($k)* [r l]) };
+    (@path rr ($($k:tt)*)) => { $crate::match_any!($($k)* [r r]) };
+    (@path lll ($($k:tt)*)) => { $crate::match_any!($($k)* [l l l]) };
+    (@path llr ($($k:tt)*)) => { $crate::match_any!($($k)* [l l r]) };
+    (@path lrl ($($k:tt)*)) => { $crate::match_any!($($k)* [l r l]) };
+    (@path lrr ($($k:tt)*)) => { $crate::match_any!($($k)* [l r r]) };
+    (@path rll ($($k:tt)*)) => { $crate::match_any!($($k)* [r l l]) };
+    (@path rlr ($($k:tt)*)) => { $crate::match_any!($($k)* [r l r]) };
+    (@path rrl ($($k:tt)*)) => { $crate::match_any!($($k)* [r r l]) };
+    (@path rrr ($($k:tt)*)) => { $crate::match_any!($($k)* [r r r]) };
+    (@path llll ($($k:tt)*)) => { $crate::match_any!($($k)* [l l l l]) };
+    (@path lllr ($($k:tt)*)) => { $crate::match_any!($($k)* [l l l r]) };
+    (@path llrl ($($k:tt)*)) => { $crate::match_any!($($k)* [l l r l]) };
+    (@path llrr ($($k:tt)*)) => { $crate::match_any!($($k)* [l l r r]) };
+    (@path lrll ($($k:tt)*)) => { $crate::match_any!($($k)* [l r l l]) };
+    (@path lrlr ($($k:tt)*)) => { $crate::match_any!($($k)* [l r l r]) };
+    (@path lrrl ($($k:tt)*)) => { $crate::match_any!($($k)* [l r r l]) };
+    (@path lrrr ($($k:tt)*)) => { $crate::match_any!($($k)* [l r r r]) };
+    (@path rlll ($($k:tt)*)) => { $crate::match_any!($($k)* [r l l l]) };
+    (@path rllr ($($k:tt)*)) => { $crate::match_any!($($k)* [r l l r]) };
+    (@path rlrl ($($k:tt)*)) => { $crate::match_any!($($k)* [r l r l]) };
+    (@path rlrr ($($k:tt)*)) => { $crate::match_any!($($k)* [r l r r]) };
+    (@path rrll ($($k:tt)*)) => { $crate::match_any!($($k)* [r r l l]) };
+    (@path rrlr ($($k:tt)*)) => { $crate::match_any!($($k)* [r r l r]) };
+    (@path rrrl ($($k:tt)*)) => { $crate::match_any!($($k)* [r r r l]) };
+    (@path rrrr ($($k:tt)*)) => { $crate::match_any!($($k)* [r r r r]) };
+    (@path $slot:ident $k:tt) => {
+        ::core::compile_error!(::core::concat!("unknown slot `", ::core::stringify!($slot), "`"))
+    };
+}
+
+/// Builds the smallest fitting [AnyOf](crate::AnyOf), [AnyOf4](crate::AnyOf4),
+/// [AnyOf8](crate::AnyOf8) or [AnyOf16](crate::AnyOf16) from 1 to 16 `Option` expressions,
+/// in leaf order.
+///
+/// - 1 or 2 expressions build an `AnyOf` (with [AnyOf::new](crate::AnyOf::new)),
+/// - 3 or 4 expressions build an `AnyOf4` (with [AnyOf4::new4](crate::AnyOf4::new4)),
+/// - 5 to 8 expressions build an `AnyOf8` (with [AnyOf8::new8](crate::AnyOf8::new8)),
+/// - 9 to 16 expressions build an `AnyOf16` (with [AnyOf16::new16](crate::AnyOf16::new16)).
+///
+/// The missing leaves are padded with `None`: their types are inferred, or default to the type
+/// of the first leaf with an `AnyOfX<T>` annotation. `any_of!()` builds a `Neither`.
+///
+/// # Examples
+/// ```rust
+/// use any_of::{any_of, AnyOf, AnyOf4, AnyOf8};
+///
+/// let any: AnyOf<i32, &str> = any_of!(Some(1), None);
+/// assert_eq!(any, AnyOf::new_left(1));
+///
+/// let any4: AnyOf4<i32> = any_of!(Some(1), None, Some(3));
+/// assert_eq!(any4, AnyOf4::new4(Some(1), None, Some(3), None));
+///
+/// let any8: AnyOf8<i32> = any_of!(None, None, None, None, Some(5));
+/// assert_eq!(any8.into_opt8().4, Some(5));
+/// ```
+#[macro_export]
+macro_rules! any_of {
+    () => { $crate::AnyOf::new_neither() };
+    ($a:expr $(,)?) => { $crate::AnyOf::new($a, ::core::option::Option::None) };
+    ($a:expr, $b:expr $(,)?) => { $crate::AnyOf::new($a, $b) };
+    ($a:expr, $b:expr, $c:expr, $d:expr, $e:expr, $f:expr, $g:expr, $h:expr, $i:expr
+        $(, $rest:expr)* $(,)?) => {
+        $crate::any_of!(@fill $crate::AnyOf16::new16;
+            [] [None None None None None None None None None None None None None None None None]
+            $a, $b, $c, $d, $e, $f, $g, $h, $i $(, $rest)*)
+    };
+    ($a:expr, $b:expr, $c:expr, $d:expr, $e:expr $(, $rest:expr)* $(,)?) => {
+        $crate::any_of!(@fill $crate::AnyOf8::new8;
+            [] [None None None None None None None None] $a, $b, $c, $d, $e $(, $rest)*)
+    };
+    ($a:expr, $b:expr, $c:expr $(, $rest:expr)* $(,)?) => {
+        $crate::any_of!(@fill $crate::AnyOf4::new4; [] [None None None None] $a, $b, $c $(, $rest)*)
+    };
+
+    // Moves each expression into a free leaf, then pads the remaining leaves with `None`.
+    (@fill $new:path; [$($done:expr,)*] [$slot:ident $($slots:ident)*] $e:expr $(, $rest:expr)*) => {
+        $crate::any_of!(@fill $new; [$($done,)* $e,] [$($slots)*] $($rest),*)
+    };
+    (@fill $new:path; [$($done:expr,)*] [$($slots:ident)*]) => {
+        $new($($done,)* $(::core::option::Option::$slots),*)
+    };
+    (@fill $new:path; [$($done:expr,)*] [] $($rest:expr),+) => {
+        ::core::compile_error!("`any_of!` takes at most 16 leaves")
+    };
+}
+
+/// Builds an [AnyOf](crate::AnyOf) with both sides: `any_of_both!(l, r)` is `AnyOf::new_both(l, r)`.
+///
+/// # Examples
+/// ```rust
+/// use any_of::{any_of_both, AnyOf};
+///
+/// assert_eq!(any_of_both!(1, "one"), AnyOf::new_both(1, "one"));
+/// ```
+#[macro_export]
+macro_rules! any_of_both {
+    ($left:expr, $right:expr $(,)?) => {
+        $crate::AnyOf::new_both($left, $right)
+    };
+}
+
+/// Builds an [AnyOf](crate::AnyOf) with a left side: `any_of_left!(l)` is `AnyOf::new_left(l)`.
+///
+/// # Examples
+/// ```rust
+/// use any_of::{any_of_left, AnyOf};
+///
+/// let any: AnyOf<i32, &str> = any_of_left!(1);
+/// assert_eq!(any, AnyOf::new_left(1));
+/// ```
+#[macro_export]
+macro_rules! any_of_left {
+    ($left:expr $(,)?) => {
+        $crate::AnyOf::new_left($left)
+    };
+}
+
+/// Builds an [AnyOf](crate::AnyOf) with a right side: `any_of_right!(r)` is `AnyOf::new_right(r)`.
+///
+/// # Examples
+/// ```rust
+/// use any_of::{any_of_right, AnyOf};
+///
+/// let any: AnyOf<i32, &str> = any_of_right!("one");
+/// assert_eq!(any, AnyOf::new_right("one"));
+/// ```
+#[macro_export]
+macro_rules! any_of_right {
+    ($right:expr $(,)?) => {
+        $crate::AnyOf::new_right($right)
+    };
+}
@@ -0,0 +1,222 @@
+//! This module provides comparators for ordering policies other than the default one.
+//!
+//! The default order (the `Ord` implementation) of [AnyOf] is `Neither < Left < Right < Both`,
+//! then the contents are compared. [BothOf] is ordered lexicographically and [EitherOf]
+//! orders `Left` before `Right`.
+//!
+//! The comparators of this module have the signature `fn(&Self, &Self) -> Ordering`,
+//! so they can be given directly to `sort_by` or `max_by`:
+//!
+//! - **left first**: [AnyOf::cmp_by_left_first] compares the optional left values first,
+//!   then the optional right values (an absent value is lower than a present one).
+//! - **presence count**: [AnyOf::cmp_by_presence] (and `cmp_by_presence4`, `cmp_by_presence8`,
+//!   `cmp_by_presence16` for [AnyOf4], [AnyOf8] and [AnyOf16]) compares the number of
+//!   present values first, then falls back to the default order.
+//!
+//! # Examples
+//!
+//! ```rust
+//! use any_of::AnyOf;
+//!
+//! let mut values: [AnyOf<i32, i32>; 4] = [
+//!     AnyOf::new_both(1, 1),
+//!     AnyOf::new_right(2),
+//!     AnyOf::new_left(3),
+//!     AnyOf::new_neither(),
+//! ];
+//!
+//! values.sort();
+//! assert_eq!(values[1], AnyOf::new_left(3));
+//!
+//! values.sort_by(AnyOf::cmp_by_left_first);
+//! assert_eq!(values[1], AnyOf::new_right(2));
+//! ```
+//!
+//! [EitherOf]: crate::EitherOf
+
+use crate::{AnyOf, AnyOf16, AnyOf4, AnyOf8, BothOf, LeftOrRight};
+use core::cmp::Ordering;
+
+impl<L, R> AnyOf<L, R> {
+    /// Returns the number of present values: `0`, `1` or `2`.
+    pub fn presence_count(&self) -> usize {
+        usize::from(self.has_left()) + usize::from(self.has_right())
+    }
+
+    /// Compares the `(Option<L>, Option<R>)` pairs lexicographically: the optional left
+    /// values, then the optional right values.
+    ///
+    /// An absent value is lower than a present one. The variants are only ordered as
+    /// `Neither < Right < Left < Both` when the values are equal: for instance,
+    /// `Left(3)` is greater than `Both(1, 1)`, as `Some(3) > Some(1)`.
+    ///
+    /// # Examples
+    /// ```rust
+    /// use any_of::AnyOf;
+    /// use core::cmp::Ordering;
+    ///
+    /// let left: AnyOf<i32, i32> = AnyOf::new_left(1);
+    /// let right: AnyOf<i32, i32> = AnyOf::new_right(1);
+    /// assert_eq!(left.cmp_by_left_first(&right), Ordering::Greater);
+    /// assert_eq!(AnyOf::new_both(1, 2).cmp_by_left_first(&AnyOf::new_both(2, 1)), Ordering::Less);
+    /// assert_eq!(AnyOf::new_left(3).cmp_by_left_first(&AnyOf::new_both(1, 1)), Ordering::Greater);
+    /// ```
+    pub fn cmp_by_left_first(&self, other: &Self) -> Ordering
+    where
+        L: Ord,
+        R: Ord,
+    {
+        self.opt2().cmp(&other.opt2())
+    }
+
+    /// Compares the number of present values, then uses the default order.
+    pub fn cmp_by_presence(&self, other: &Self) -> Ordering
+    where
+        L: Ord,
+        R: Ord,
+    {
+        self.presence_count()
+            .cmp(&other.presence_count())
+            .then_with(|| self.cmp(other))
+    }
+}
+
+impl<L, R> BothOf<L, R> {
+    /// Compares the right values, then the left values.
+    ///
+    /// # Examples
+    /// ```rust
+    /// use any_of::BothOf;
+    /// use core::cmp::Ordering;
+    ///
+    /// let a = BothOf::new(1, 2);
+    /// let b = BothOf::new(2, 1);
+    /// assert_eq!(a.cmp(&b), Ordering::Less);
+    /// assert_eq!(a.cmp_by_right_first(&b), Ordering::Greater);
+    /// ```
+    pub fn cmp_by_right_first(&self, other: &Self) -> Ordering
+    where
+        L: Ord,
+        R: Ord,
+    {
+        (&self.right, &self.left).cmp(&(&other.right, &other.left))
+    }
+}
+
+impl<LL, LR, RL, RR> AnyOf4<LL, LR, RL, RR> {
+    /// Returns the number of present leaves, from `0` to `4`.
+    pub fn presence_count4(&self) -> usize {
+        let (ll, lr, rl, rr) = self.opt4();
+        [ll.is_some(), lr.is_some(), rl.is_some(), rr.is_some()]
+            .iter()
+            .filter(|&&x| x)
+            .count()
+    }
+
+    /// Compares the number of present leaves, then uses the default order.
+    pub fn cmp_by_presence4(&self, other: &Self) -> Ordering
+    where
+        LL: Ord,
+        LR: Ord,
+        RL: Ord,
+        RR: Ord,
+    {
+        self.presence_count4()
+            .cmp(&other.presence_count4())
+            .then_with(|| self.cmp(other))
+    }
+}
+
+impl<LLL, LLR, LRL, LRR, RLL, RLR, RRL, RRR> AnyOf8<LLL, LLR, LRL, LRR, RLL, RLR, RRL, RRR> {
+    /// Returns the number of present leaves, from `0` to `8`.
+    pub fn presence_count8(&self) -> usize {
+        self.left().map_or(0, AnyOf4::presence_count4)
+            + self.right().map_or(0, AnyOf4::presence_count4)
+    }
+
+    /// Compares the number of present leaves, then uses the default order.
+    pub fn cmp_by_presence8(&self, other: &Self) -> Ordering
+    where
+        LLL: Ord,
+        LLR: Ord,
+        LRL: Ord,
+        LRR: Ord,
+        RLL: Ord,
+        RLR: Ord,
+        RRL: Ord,
+        RRR: Ord,
+    {
+        self.presence_count8()
+            .cmp(&other.presence_count8())
+            .then_with(|| self.cmp(other))
+    }
+}
+
+impl<
+        LLLL,
+        LLLR,
+        LLRL,
+        LLRR,
+        LRLL,
+        LRLR,
+        LRRL,
+        LRRR,
+        RLLL,
+        RLLR,
+        RLRL,
+        RLRR,
+        RRLL,
+        RRLR,
+        RRRL,
+        RRRR,
+    >
+    AnyOf16<
+        LLLL,
+        LLLR,
+        LLRL,
+        LLRR,
+        LRLL,
+        LRLR,
+        LRRL,
+        LRRR,
+        RLLL,
+        RLLR,
+        RLRL,
+        RLRR,
+        RRLL,
+        RRLR,
+        RRRL,
+        RRRR,
+    >
+{
+    /// Returns the number of present leaves, from `0` to `16`.
+    pub fn presence_count16(&self) -> usize {
+        self.left().map_or(0, AnyOf8::presence_count8)
+            + self.right().map_or(0, AnyOf8::presence_count8)
+    }
+
+    /// Compares the number of present leaves, then uses the default order.
+    pub fn cmp_by_presence16(&self, other: &Self) -> Ordering
+    where
+        LLLL: Ord,
+        LLLR: Ord,
+        LLRL: Ord,
+        LLRR: Ord,
+        LRLL: Ord,
+        LRLR: Ord,
+        LRRL: Ord,
+        LRRR: Ord,
+        RLLL: Ord,
+        RLLR: Ord,
+        RLRL: Ord,
+        RLRR: Ord,
+        RRLL: Ord,
+        RRLR: Ord,
+        RRRL: Ord,
+        RRRR: Ord,
+    {
+        self.presence_count16()
+            .cmp(&other.presence_count16())
+            .then_with(|| self.cmp(other))
+    }
+}
@@ -0,0 +1,285 @@
+//! This module provides `PackedAnyOf`, a layout-optimized representation of [AnyOf].
+//!
+//! `AnyOf<L, R>` is a nested enum (an `AnyOf` containing an `EitherOf` or a `BothOf`),
+//! so its size depends on the discriminant and the padding of each layer.
+//! `PackedAnyOf<L, R>` stores the two values side by side, without discriminant,
+//! and the presence of each value in a single tag byte.
+//!
+//! It is only smaller when `L` and `R` have different alignments: `AnyOf` then pads its
+//! discriminants to the larger alignment. When they have the same alignment, `Both` already
+//! needs both values and a tag, and the sizes are equal. The values cannot share a union, as
+//! `Both` holds the two of them.
+//!
+//! | `L`, `R`          | `AnyOf` | `PackedAnyOf` |
+//! |-------------------|---------|---------------|
+//! | `u64`, `u8`       | 24      | 16            |
+//! | `u16`, `u32`      | 12      | 8             |
+//! | `u32`, `u32`      | 12      | 12            |
+//! | `[u16; 3]`, `u16` | 10      | 10            |
+//!
+//! `PackedAnyOf` implements [LeftOrRight], [Map], [Unwrap] and [Swap], and converts
+//! losslessly from and into [AnyOf].
+//! As it implements `Drop`, it cannot be `Copy`.
+//!
+//! # Examples
+//!
+//! ```rust
+//! use any_of::{AnyOf, LeftOrRight, Map, PackedAnyOf};
+//!
+//! let packed: PackedAnyOf<u64, u8> = PackedAnyOf::new_both(42, 1);
+//! assert!(packed.is_both());
+//! assert_eq!(packed.left(), Some(&42));
+//!
+//! let mapped = packed.map(|l| l * 2, |r| r + 1);
+//! assert_eq!(mapped.into_any_of(), AnyOf::new_both(84, 2));
+//! assert!(size_of::<PackedAnyOf<u64, u8>>() < size_of::<AnyOf<u64, u8>>());
+//! ```
+
+use crate::concepts::{Map, Opt2, Swap, Unwrap};
+use crate::{AnyOf, Left, LeftOrRight, Right};
+use core::cmp::Ordering;
+use core::fmt::{Debug, Formatter};
+use core::hash::{Hash, Hasher};
+use core::mem::{ManuallyDrop, MaybeUninit};
+use core::ops::Not;
+
+pub(crate) const LEFT: u8 = 0b01;
+pub(crate) const RIGHT: u8 = 0b10;
+
+/// A representation of [AnyOf] with a single tag byte.
+///
+/// The tag bit `0b01` is set when the left value is present,
+/// and the tag bit `0b10` is set when the right value is present.
+pub struct PackedAnyOf<L, R = L> {
+    left: MaybeUninit<L>,
+    right: MaybeUninit<R>,
+    tag: u8,
+}
+
+impl<L, R> PackedAnyOf<L, R> {
+    /// Creates a new `PackedAnyOf` based on the presence of `left` and `right` values.
+    ///
+    /// See [AnyOf::new].
+    pub fn new(left: Option<L>, right: Option<R>) -> Self {
+        let mut tag = 0;
+        let left = match left {
+            Some(l) => {
+                tag |= LEFT;
+                MaybeUninit::new(l)
+            }
+            None => MaybeUninit::uninit(),
+        };
+        let right = match right {
+            Some(r) => {
+                tag |= RIGHT;
+                MaybeUninit::new(r)
+            }
+            None => MaybeUninit::uninit(),
+        };
+        Self { left, right, tag }
+    }
+
+    /// Creates a `PackedAnyOf` without values.
+    pub fn new_neither() -> Self {
+        Self::new(None, None)
+    }
+
+    /// Creates a `PackedAnyOf` with a left value.
+    pub fn new_left(left: L) -> Self {
+        Self::new(Some(left), None)
+    }
+
+    /// Creates a `PackedAnyOf` with a right value.
+    pub fn new_right(right: R) -> Self {
+        Self::new(None, Some(right))
+    }
+
+    /// Creates a `PackedAnyOf` with both values.
+    pub fn new_both(left: L, right: R) -> Self {
+        Self::new(Some(left), Some(right))
+    }
+
+    /// Creates a `PackedAnyOf` from an [AnyOf].
+    pub fn from_any_of(any: AnyOf<L, R>) -> Self {
+        match any {
+            AnyOf::Neither => Self::new_neither(),
+            AnyOf::Either(Left(l)) => Self::new_left(l),
+            AnyOf::Either(Right(r)) => Self::new_right(r),
+            AnyOf::Both(b) => Self::new_both(b.left, b.right),
+        }
+    }
+
+    /// Converts into an [AnyOf].
+    pub fn into_any_of(self) -> AnyOf<L, R> {
+        AnyOf::from_opt2(self.into_opt2())
+    }
+
+    /// Converts into a pair of options.
+    pub fn into_opt2(self) -> Opt2<L, R> {
+        let this = ManuallyDrop::new(self);
+        // SAFETY: the values are read once according to the tag, and `this` is not dropped.
+        unsafe {
+            (
+                this.has_left().then(|| this.left.assume_init_read()),
+                this.has_right().then(|| this.right.assume_init_read()),
+            )
+        }
+    }
+
+    /// Returns an [AnyOf] of references to the values.
+    pub fn as_any_of(&self) -> AnyOf<&L, &R> {
+        AnyOf::new(self.left(), self.right())
+    }
+
+    /// Returns the tag: `0b01` if the left value is present, `0b10` if the right value is present.
+    pub fn tag(&self) -> u8 {
+        self.tag
+    }
+
+    /// True if the left value is present.
+    pub fn has_left(&self) -> bool {
+        self.tag & LEFT != 0
+    }
+
+    /// True if the right value is present.
+    pub fn has_right(&self) -> bool {
+        self.tag & RIGHT != 0
+    }
+
+    /// True if no value is present.
+    pub fn is_neither(&self) -> bool {
+        self.tag == 0
+    }
+
+    /// True if exactly one value is present.
+    pub fn is_either(&self) -> bool {
+        self.tag == LEFT || self.tag == RIGHT
+    }
+
+    /// True if both values are present.
+    pub fn is_both(&self) -> bool {
+        self.tag == LEFT | RIGHT
+    }
+}
+
+impl<L, R> Drop for PackedAnyOf<L, R> {
+    fn drop(&mut self) {
+        // SAFETY: only the values marked as present by the tag are initialized.
+        unsafe {
+            if self.has_left() {
+                self.left.assume_init_drop();
+            }
+            if self.has_right() {
+                self.right.assume_init_drop();
+            }
+        }
+    }
+}
+
+impl<L, R> LeftOrRight<L, R> for PackedAnyOf<L, R> {
+    fn left(&self) -> Option<&L> {
+        // SAFETY: the left value is initialized when its tag bit is set.
+        self.has_left()
+            .then(|| unsafe { self.left.assume_init_ref() })
+    }
+
+    fn right(&self) -> Option<&R> {
+        // SAFETY: the right value is initialized when its tag bit is set.
+        self.has_right()
+            .then(|| unsafe { self.right.assume_init_ref() })
+    }
+}
+
+impl<L, R> Not for PackedAnyOf<L, R> {
+    type Output = PackedAnyOf<R, L>;
+
+    /// Swaps (`!` operator) the left and right values.
+    fn not(self) -> Self::Output {
+        let (left, right) = self.into_opt2();
+        PackedAnyOf::new(right, left)
+    }
+}
+
+impl<L, R> Swap<L, R> for PackedAnyOf<L, R> {
+    type Output = <Self as Not>::Output;
+}
+
+impl<L, R> Map<L, R> for PackedAnyOf<L, R> {
+    type Output<L2, R2> = PackedAnyOf<L2, R2>;
+
+    fn map<FL, FR, L2, R2>(self, fl: FL, fr: FR) -> Self::Output<L2, R2>
+    where
+        FL: FnOnce(L) -> L2,
+        FR: FnOnce(R) -> R2,
+    {
+        let (left, right) = self.into_opt2();
+        PackedAnyOf::new(left.map(fl), right.map(fr))
+    }
+}
+
+impl<L, R> Unwrap<L, R> for PackedAnyOf<L, R> {
+    fn left_or_else(self, f: impl FnOnce() -> L) -> L {
+        self.into_opt2().0.unwrap_or_else(f)
+    }
+
+    fn right_or_else(self, f: impl FnOnce() -> R) -> R {
+        self.into_opt2().1.unwrap_or_else(f)
+    }
+}
+
+impl<L, R> From<AnyOf<L, R>> for PackedAnyOf<L, R> {
+    /// See [PackedAnyOf::from_any_of].
+    fn from(value: AnyOf<L, R>) -> Self {
+        Self::from_any_of(value)
+    }
+}
+
+impl<L, R> From<PackedAnyOf<L, R>> for AnyOf<L, R> {
+    /// See [PackedAnyOf::into_any_of].
+    fn from(value: PackedAnyOf<L, R>) -> Self {
+        value.into_any_of()
+    }
+}
+
+impl<L: Clone, R: Clone> Clone for PackedAnyOf<L, R> {
+    fn clone(&self) -> Self {
+        Self::new(self.left().cloned(), self.right().cloned())
+    }
+}
+
+impl<L: Debug, R: Debug> Debug for PackedAnyOf<L, R> {
+    /// Formats as the equivalent [AnyOf].
+    fn fmt(&self, f: &mut Formatter<'_>) -> core::fmt::Result {
+        f.debug_tuple("PackedAnyOf")
+            .field(&self.as_any_of())
+            .finish()
+    }
+}
+
+impl<L: PartialEq, R: PartialEq> PartialEq for PackedAnyOf<L, R> {
+    fn eq(&self, other: &Self) -> bool {
+        self.as_any_of() == other.as_any_of()
+    }
+}
+
+impl<L: Eq, R: Eq> Eq for PackedAnyOf<L, R> {}
+
+impl<L: PartialOrd, R: PartialOrd> PartialOrd for PackedAnyOf<L, R> {
+    /// Same order as [AnyOf].
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        self.as_any_of().partial_cmp(&other.as_any_of())
+    }
+}
+
+impl<L: Ord, R: Ord> Ord for PackedAnyOf<L, R> {
+    /// Same order as [AnyOf].
+    fn cmp(&self, other: &Self) -> Ordering {
+        self.as_any_of().cmp(&other.as_any_of())
+    }
+}
+
+impl<L: Hash, R: Hash> Hash for PackedAnyOf<L, R> {
+    fn hash<H: Hasher>(&self, state: &mut H) {
+        self.as_any_of().hash(state)
+    }
+}
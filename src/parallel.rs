@@ -0,0 +1,286 @@
+//! This module provides the `rayon` support of the `rayon` feature.
+//!
+//! - [EitherOf] implements `ParallelIterator` (and `IndexedParallelIterator`) when both sides
+//!   have the same item type, delegating to the contained iterator.
+//!   As `EitherOf` also implements [crate::Map], call `ParallelIterator::map` explicitly
+//!   when both traits are in scope.
+//! - [ParallelPartition::partition_both] partitions a parallel iterator into a `BothOf<Vec<L>, Vec<R>>`.
+//! - `AnyOf<Vec<L>, Vec<R>>` implements `FromParallelIterator` for items of type [AnyOf] and [EitherOf].
+//! - [par_outer_join] and [par_outer_join_by_key] are the parallel versions of
+//!   [crate::join::outer_join] and [crate::join::outer_join_by_key].
+//!
+//! # Examples
+//!
+//! ```rust
+//! use any_of::parallel::ParallelPartition;
+//! use any_of::{AnyOf, BothOf, Left, Right};
+//! use rayon::prelude::*;
+//!
+//! let both = (0..10).into_par_iter().partition_both(|n| if n % 2 == 0 { Left(n) } else { Right(n) });
+//! assert_eq!(both, BothOf::new(vec![0, 2, 4, 6, 8], vec![1, 3, 5, 7, 9]));
+//!
+//! let any: AnyOf<Vec<i32>, Vec<i32>> = (0..3).into_par_iter().map(AnyOf::new_left).collect();
+//! assert_eq!(any, AnyOf::new_left(vec![0, 1, 2]));
+//! ```
+
+use crate::join::outer_join_by_key;
+use crate::{AnyOf, BothOf, EitherOf, Left, Right};
+use alloc::vec::Vec;
+use rayon::iter::plumbing::{Consumer, ProducerCallback, UnindexedConsumer};
+use rayon::iter::{
+    FromParallelIterator, IndexedParallelIterator, IntoParallelIterator, ParallelIterator,
+};
+
+impl<L, R> ParallelIterator for EitherOf<L, R>
+where
+    L: ParallelIterator,
+    R: ParallelIterator<Item = L::Item>,
+{
+    type Item = L::Item;
+
+    fn drive_unindexed<C>(self, consumer: C) -> C::Result
+    where
+        C: UnindexedConsumer<Self::Item>,
+    {
+        match self {
+            Left(l) => l.drive_unindexed(consumer),
+            Right(r) => r.drive_unindexed(consumer),
+        }
+    }
+
+    fn opt_len(&self) -> Option<usize> {
+        match self {
+            Left(l) => l.opt_len(),
+            Right(r) => r.opt_len(),
+        }
+    }
+}
+
+impl<L, R> IndexedParallelIterator for EitherOf<L, R>
+where
+    L: IndexedParallelIterator,
+    R: IndexedParallelIterator<Item = L::Item>,
+{
+    fn drive<C: Consumer<Self::Item>>(self, consumer: C) -> C::Result {
+        match self {
+            Left(l) => l.drive(consumer),
+            Right(r) => r.drive(consumer),
+        }
+    }
+
+    fn len(&self) -> usize {
+        match self {
+            Left(l) => l.len(),
+            Right(r) => r.len(),
+        }
+    }
+
+    fn with_producer<CB: ProducerCallback<Self::Item>>(self, callback: CB) -> CB::Output {
+        match self {
+            Left(l) => l.with_producer(callback),
+            Right(r) => r.with_producer(callback),
+        }
+    }
+}
+
+fn into_rayon_either<L, R>(either: EitherOf<L, R>) -> rayon::iter::Either<L, R> {
+    match either {
+        Left(l) => rayon::iter::Either::Left(l),
+        Right(r) => rayon::iter::Either::Right(r),
+    }
+}
+
+/// Parallel partitions into a [BothOf] of vectors.
+///
+/// This trait is implemented for all the parallel iterators.
+pub trait ParallelPartition: ParallelIterator {
+    /// Partitions the items with `f`: the `Left` values are collected on the left,
+    /// and the `Right` values on the right, in order.
+    fn partition_both<L, R, F>(self, f: F) -> BothOf<Vec<L>, Vec<R>>
+    where
+        L: Send,
+        R: Send,
+        F: Fn(Self::Item) -> EitherOf<L, R> + Sync + Send,
+    {
+        let (left, right) = self.partition_map(move |item| into_rayon_either(f(item)));
+        BothOf::new(left, right)
+    }
+
+    /// Collects the left and right values of the [AnyOf] items, in order.
+    ///
+    /// The two values of a `Both` item are collected on each side.
+    fn unzip_any<L, R>(self) -> BothOf<Vec<L>, Vec<R>>
+    where
+        Self: ParallelIterator<Item = AnyOf<L, R>>,
+        L: Send,
+        R: Send,
+    {
+        let (left, right) = self
+            .flat_map_iter(|any| {
+                let (left, right) = any.into_opt2();
+                left.map(Left).into_iter().chain(right.map(Right))
+            })
+            .partition_map(into_rayon_either);
+        BothOf::new(left, right)
+    }
+}
+
+impl<I: ParallelIterator> ParallelPartition for I {}
+
+fn non_empty<T>(values: Vec<T>) -> Option<Vec<T>> {
+    (!values.is_empty()).then_some(values)
+}
+
+impl<L: Send, R: Send> FromParallelIterator<AnyOf<L, R>> for AnyOf<Vec<L>, Vec<R>> {
+    /// Collects the left and right values, see [ParallelPartition::unzip_any].
+    ///
+    /// An empty side is `None`: no item gives `Neither`.
+    fn from_par_iter<I>(iter: I) -> Self
+    where
+        I: IntoParallelIterator<Item = AnyOf<L, R>>,
+    {
+        let both = iter.into_par_iter().unzip_any();
+        AnyOf::new(non_empty(both.left), non_empty(both.right))
+    }
+}
+
+impl<L: Send, R: Send> FromParallelIterator<EitherOf<L, R>> for AnyOf<Vec<L>, Vec<R>> {
+    /// Collects the left and right values, see [ParallelPartition::partition_both].
+    ///
+    /// An empty side is `None`: no item gives `Neither`.
+    fn from_par_iter<I>(iter: I) -> Self
+    where
+        I: IntoParallelIterator<Item = EitherOf<L, R>>,
+    {
+        let both = iter.into_par_iter().partition_both(|either| either);
+        AnyOf::new(non_empty(both.left), non_empty(both.right))
+    }
+}
+
+/// Below this number of elements, the join is sequential.
+const SEQUENTIAL_THRESHOLD: usize = 4096;
+
+/// Joins two slices sorted by the keys returned by `left_key` and `right_key`, in parallel.
+///
+/// The result is the same as the one of [crate::join::outer_join_by_key]: the slices are split
+/// at the boundaries of key groups, the parts are joined in parallel and concatenated in order.
+///
+/// # Examples
+/// ```rust
+/// use any_of::parallel::par_outer_join_by_key;
+/// use any_of::AnyOf;
+///
+/// let left: Vec<u32> = (0..10_000).map(|n| n * 2).collect();
+/// let right: Vec<u32> = (0..10_000).map(|n| n * 3).collect();
+/// let joined = par_outer_join_by_key(&left, &right, |l| *l, |r| *r);
+/// assert_eq!(joined.iter().filter(|any| any.is_both()).count(), 3334);
+/// assert_eq!(joined[2], AnyOf::new_right(&3));
+/// ```
+pub fn par_outer_join_by_key<'a, L, R, K, FL, FR>(
+    left: &'a [L],
+    right: &'a [R],
+    left_key: FL,
+    right_key: FR,
+) -> Vec<AnyOf<&'a L, &'a R>>
+where
+    L: Sync,
+    R: Sync,
+    K: Ord,
+    FL: Fn(&'a L) -> K + Sync,
+    FR: Fn(&'a R) -> K + Sync,
+{
+    let mut joined = Vec::new();
+    join_into(left, right, &left_key, &right_key, &mut joined);
+    joined
+}
+
+/// Joins two sorted slices of the same type, in parallel.
+///
+/// See [par_outer_join_by_key].
+pub fn par_outer_join<'a, T: Ord + Sync>(
+    left: &'a [T],
+    right: &'a [T],
+) -> Vec<AnyOf<&'a T, &'a T>> {
+    par_outer_join_by_key(left, right, |l| l, |r| r)
+}
+
+fn join_into<'a, L, R, K, FL, FR>(
+    left: &'a [L],
+    right: &'a [R],
+    left_key: &FL,
+    right_key: &FR,
+    joined: &mut Vec<AnyOf<&'a L, &'a R>>,
+) where
+    L: Sync,
+    R: Sync,
+    K: Ord,
+    FL: Fn(&'a L) -> K + Sync,
+    FR: Fn(&'a R) -> K + Sync,
+{
+    let total = left.len() + right.len();
+    let split = if total <= SEQUENTIAL_THRESHOLD {
+        None
+    } else {
+        split_point(left, right, left_key, right_key)
+    };
+    match split {
+        None => joined.extend(outer_join_by_key(left, right, left_key, right_key)),
+        Some((l, r)) => {
+            let (left, left_rest) = left.split_at(l);
+            let (right, right_rest) = right.split_at(r);
+            let mut rest = Vec::new();
+            rayon::join(
+                || join_into(left, right, left_key, right_key, joined),
+                || join_into(left_rest, right_rest, left_key, right_key, &mut rest),
+            );
+            joined.append(&mut rest);
+        }
+    }
+}
+
+/// Returns the positions where both slices can be split without separating elements
+/// with the same key, or `None` if the split would leave a side empty.
+fn split_point<'a, L, R, K, FL, FR>(
+    left: &'a [L],
+    right: &'a [R],
+    left_key: &FL,
+    right_key: &FR,
+) -> Option<(usize, usize)>
+where
+    K: Ord,
+    FL: Fn(&'a L) -> K,
+    FR: Fn(&'a R) -> K,
+{
+    let pivot = if left.len() >= right.len() {
+        left_key(&left[left.len() / 2])
+    } else {
+        right_key(&right[right.len() / 2])
+    };
+    let total = left.len() + right.len();
+    // Split before the pivot group, or after it if nothing comes before it.
+    let before = (
+        partition_point(left, |x| left_key(x) < pivot),
+        partition_point(right, |x| right_key(x) < pivot),
+    );
+    let after = (
+        partition_point(left, |x| left_key(x) <= pivot),
+        partition_point(right, |x| right_key(x) <= pivot),
+    );
+    [before, after]
+        .into_iter()
+        .find(|&(l, r)| l + r != 0 && l + r != total)
+}
+
+/// Same as `slice::partition_point`, with the lifetime of the slice given to `pred`.
+fn partition_point<'a, T>(slice: &'a [T], pred: impl Fn(&'a T) -> bool) -> usize {
+    let (mut low, mut high) = (0, slice.len());
+    while low < high {
+        let mid = low + (high - low) / 2;
+        if pred(&slice[mid]) {
+            low = mid + 1;
+        } else {
+            high = mid;
+        }
+    }
+    low
+}
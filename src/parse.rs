@@ -0,0 +1,403 @@
+//! This module provides `FromStr` for [EitherOf], [BothOf] and [AnyOf], parsing the format of
+//! their `Display` implementations.
+//!
+//! # Grammar
+//!
+//! ```text
+//! any_of    = "_" | either_of | both_of
+//! either_of = "L(" value ")" | "R(" value ")"
+//! both_of   = "B(" value ", " value ")"
+//! ```
+//!
+//! A `value` is the `Display` of its side, written as is when it contains no `\`, no
+//! unbalanced parentheses and no `, ` outside of parentheses, and escaped otherwise: each `\`,
+//! `(`, `)` and `,` is then preceded by a `\`. As [AnyOf4], [AnyOf8] and [AnyOf16] are nested
+//! `AnyOf`, their values are themselves `any_of` values: `B(L(1), R(2))` is an `AnyOf4` with
+//! the leaves `ll` and `rr`.
+//!
+//! The parser is strict (no whitespace is skipped) and delimits the values by the unescaped
+//! parentheses and the first unescaped `, ` outside of parentheses. A value containing a `\` is
+//! unescaped before being parsed with the `FromStr` implementation of its side, which needs
+//! the `alloc` feature: without it, such a value is a [ParseError::Syntax].
+//!
+//! The errors ([ParseError]) give the byte position in the parsed input where parsing failed.
+//! The position of a [ParseError::Left] or [ParseError::Right] is the start of the value, and
+//! the positions in its nested `error` are relative to that value (once unescaped).
+//!
+//! # Examples
+//!
+//! ```rust
+//! use any_of::{AnyOf, AnyOf4};
+//! use any_of::parse::ParseError;
+//!
+//! let any: AnyOf<i32, bool> = "B(42, true)".parse().unwrap();
+//! assert_eq!(any, AnyOf::new_both(42, true));
+//!
+//! let any4: AnyOf4<i32> = "B(L(1), R(2))".parse().unwrap();
+//! assert_eq!(any4, AnyOf4::new4(Some(1), None, None, Some(2)));
+//! assert_eq!(any4.to_string().parse(), Ok(any4));
+//!
+//! let error = "B(42 true)".parse::<AnyOf<i32, bool>>().unwrap_err();
+//! assert_eq!(error, ParseError::Syntax { position: 9, expected: "`, `" });
+//! ```
+//!
+//! Values which would be ambiguous are escaped:
+//!
+//! ```rust
+//! # #[cfg(feature = "alloc")] {
+//! use any_of::AnyOf;
+//!
+//! let any: AnyOf<String, String> = AnyOf::new_both("a, b".into(), "(c".into());
+//! assert_eq!(any.to_string(), r"B(a\, b, \(c)");
+//! assert_eq!(any.to_string().parse(), Ok(any));
+//! # }
+//! ```
+
+use crate::{AnyOf, BothOf, EitherOf, Left, Right};
+#[cfg(doc)]
+use crate::{AnyOf4, AnyOf8, AnyOf16};
+#[cfg(feature = "alloc")]
+use alloc::string::String;
+use core::error::Error;
+use core::fmt::{Display, Formatter, Write};
+use core::str::FromStr;
+
+/// The error returned when parsing an [EitherOf], a [BothOf] or an [AnyOf].
+#[derive(Copy, Clone, Eq, PartialEq, Debug, Hash)]
+pub enum ParseError<EL, ER> {
+    /// The input does not follow the grammar: `expected` was expected at the byte `position`.
+    Syntax {
+        position: usize,
+        expected: &'static str,
+    },
+    /// The left value, starting at the byte `position`, failed to parse.
+    Left { position: usize, error: EL },
+    /// The right value, starting at the byte `position`, failed to parse.
+    Right { position: usize, error: ER },
+}
+
+impl<EL: Display, ER: Display> Display for ParseError<EL, ER> {
+    fn fmt(&self, f: &mut Formatter<'_>) -> core::fmt::Result {
+        match self {
+            ParseError::Syntax { position, expected } => {
+                write!(f, "expected {expected} at position {position}")
+            }
+            ParseError::Left { position, error } => {
+                write!(f, "invalid left value at position {position}: {error}")
+            }
+            ParseError::Right { position, error } => {
+                write!(f, "invalid right value at position {position}: {error}")
+            }
+        }
+    }
+}
+
+impl<EL: Error + 'static, ER: Error + 'static> Error for ParseError<EL, ER> {
+    fn source(&self) -> Option<&(dyn Error + 'static)> {
+        match self {
+            ParseError::Syntax { .. } => None,
+            ParseError::Left { error, .. } => Some(error),
+            ParseError::Right { error, .. } => Some(error),
+        }
+    }
+}
+
+/// Formats a value of the `Display` format, escaping it when it would not parse back as is.
+///
+/// The value is formatted twice: once to scan it, and once to write it.
+pub(crate) struct DisplayValue<'a, T>(pub(crate) &'a T);
+
+impl<T: Display> Display for DisplayValue<'_, T> {
+    fn fmt(&self, f: &mut Formatter<'_>) -> core::fmt::Result {
+        let mut scan = Scan::default();
+        write!(scan, "{}", self.0)?;
+        if scan.is_verbatim() {
+            write!(f, "{}", self.0)
+        } else {
+            write!(Escape(f), "{}", self.0)
+        }
+    }
+}
+
+/// Scans a formatted value for what would prevent parsing it back as is.
+#[derive(Default)]
+struct Scan {
+    depth: usize,
+    unbalanced: bool,
+    backslash: bool,
+    comma: bool,
+    separator: bool,
+}
+
+impl Scan {
+    fn scan(&mut self, s: &str) {
+        for byte in s.bytes() {
+            self.separator |= self.comma && byte == b' ';
+            self.comma = byte == b',' && self.depth == 0;
+            match byte {
+                b'\\' => self.backslash = true,
+                b'(' => self.depth += 1,
+                b')' if self.depth == 0 => self.unbalanced = true,
+                b')' => self.depth -= 1,
+                _ => {}
+            }
+        }
+    }
+
+    fn is_verbatim(&self) -> bool {
+        self.depth == 0 && !self.unbalanced && !self.backslash && !self.separator
+    }
+}
+
+impl Write for Scan {
+    fn write_str(&mut self, s: &str) -> core::fmt::Result {
+        self.scan(s);
+        Ok(())
+    }
+}
+
+/// Writes `s` with `write`, preceding each `\`, `(`, `)` and `,` by a `\`.
+fn escape<E>(s: &str, mut write: impl FnMut(&str) -> Result<(), E>) -> Result<(), E> {
+    let mut start = 0;
+    for (i, byte) in s.bytes().enumerate() {
+        if matches!(byte, b'\\' | b'(' | b')' | b',') {
+            write(&s[start..i])?;
+            write("\\")?;
+            start = i;
+        }
+    }
+    write(&s[start..])
+}
+
+/// Writes to a formatter, escaping the written strings.
+struct Escape<'a, 'b>(&'a mut Formatter<'b>);
+
+impl Write for Escape<'_, '_> {
+    fn write_str(&mut self, s: &str) -> core::fmt::Result {
+        escape(s, |s| self.0.write_str(s))
+    }
+}
+
+#[cfg(feature = "ufmt")]
+impl<T: ufmt::uDisplay> ufmt::uDisplay for DisplayValue<'_, T> {
+    fn fmt<W>(&self, f: &mut ufmt::Formatter<'_, W>) -> Result<(), W::Error>
+    where
+        W: ufmt::uWrite + ?Sized,
+    {
+        let mut scan = Scan::default();
+        let Ok(()) = self.0.fmt(&mut ufmt::Formatter::new(&mut scan));
+        if scan.is_verbatim() {
+            self.0.fmt(f)
+        } else {
+            self.0.fmt(&mut ufmt::Formatter::new(&mut UfmtEscape(f)))
+        }
+    }
+}
+
+#[cfg(feature = "ufmt")]
+impl ufmt::uWrite for Scan {
+    type Error = core::convert::Infallible;
+
+    fn write_str(&mut self, s: &str) -> Result<(), Self::Error> {
+        self.scan(s);
+        Ok(())
+    }
+}
+
+/// Writes to a `ufmt` formatter, escaping the written strings.
+#[cfg(feature = "ufmt")]
+struct UfmtEscape<'a, 'b, W: ufmt::uWrite + ?Sized>(&'a mut ufmt::Formatter<'b, W>);
+
+#[cfg(feature = "ufmt")]
+impl<W: ufmt::uWrite + ?Sized> ufmt::uWrite for UfmtEscape<'_, '_, W> {
+    type Error = W::Error;
+
+    fn write_str(&mut self, s: &str) -> Result<(), Self::Error> {
+        escape(s, |s| self.0.write_str(s))
+    }
+}
+
+/// A value to parse and its byte position in the input.
+type Value<'a> = (usize, &'a str);
+
+/// The syntactic parts of a formatted value.
+enum Parts<'a> {
+    Neither,
+    Left(Value<'a>),
+    Right(Value<'a>),
+    Both(Value<'a>, Value<'a>),
+}
+
+/// Splits `s` into its parts, accepting only the first characters in `tags`.
+fn split<'a, EL, ER>(
+    s: &'a str,
+    tags: &str,
+    expected: &'static str,
+) -> Result<Parts<'a>, ParseError<EL, ER>> {
+    let syntax = |position, expected| ParseError::Syntax { position, expected };
+    let tag = match s.chars().next() {
+        Some(tag) if tags.contains(tag) => tag,
+        _ => return Err(syntax(0, expected)),
+    };
+    if tag == '_' {
+        return if s.len() == 1 {
+            Ok(Parts::Neither)
+        } else {
+            Err(syntax(1, "end of input"))
+        };
+    }
+    if !s[1..].starts_with('(') {
+        return Err(syntax(1, "`(`"));
+    }
+    let close = closing_parenthesis(s, 2).ok_or(syntax(s.len(), "`)`"))?;
+    if close + 1 != s.len() {
+        return Err(syntax(close + 1, "end of input"));
+    }
+    let content = &s[2..close];
+    match tag {
+        'L' => Ok(Parts::Left((2, content))),
+        'R' => Ok(Parts::Right((2, content))),
+        _ => {
+            let separator = top_level_separator(content).ok_or(syntax(close, "`, `"))?;
+            let right = separator + 2;
+            Ok(Parts::Both(
+                (2, &content[..separator]),
+                (2 + right, &content[right..]),
+            ))
+        }
+    }
+}
+
+/// Returns the position of the unescaped `)` closing the parenthesis opened just before `start`.
+fn closing_parenthesis(s: &str, start: usize) -> Option<usize> {
+    let mut depth = 0usize;
+    let mut escaped = false;
+    for (i, byte) in s.bytes().enumerate().skip(start) {
+        match byte {
+            _ if escaped => escaped = false,
+            b'\\' => escaped = true,
+            b'(' => depth += 1,
+            b')' if depth == 0 => return Some(i),
+            b')' => depth -= 1,
+            _ => {}
+        }
+    }
+    None
+}
+
+/// Returns the position of the first unescaped `, ` outside of parentheses.
+fn top_level_separator(s: &str) -> Option<usize> {
+    let bytes = s.as_bytes();
+    let mut depth = 0usize;
+    let mut escaped = false;
+    for (i, &byte) in bytes.iter().enumerate() {
+        match byte {
+            _ if escaped => escaped = false,
+            b'\\' => escaped = true,
+            b'(' => depth += 1,
+            b')' => depth = depth.saturating_sub(1),
+            b',' if depth == 0 && bytes.get(i + 1) == Some(&b' ') => return Some(i),
+            _ => {}
+        }
+    }
+    None
+}
+
+/// Parses a value, unescaping it first if it contains a `\`.
+fn parse_value<T: FromStr, EL, ER>(
+    (position, s): Value,
+    error: impl FnOnce(usize, T::Err) -> ParseError<EL, ER>,
+) -> Result<T, ParseError<EL, ER>> {
+    let Some(_backslash) = s.find('\\') else {
+        return s.parse().map_err(|e| error(position, e));
+    };
+    #[cfg(feature = "alloc")]
+    {
+        let mut unescaped = String::with_capacity(s.len());
+        let mut chars = s.chars();
+        while let Some(c) = chars.next() {
+            unescaped.extend(if c == '\\' { chars.next() } else { Some(c) });
+        }
+        unescaped.parse().map_err(|e| error(position, e))
+    }
+    #[cfg(not(feature = "alloc"))]
+    Err(ParseError::Syntax {
+        position: position + _backslash,
+        expected: "a value without `\\` (unescaping requires the `alloc` feature)",
+    })
+}
+
+fn parse_left<L: FromStr, ER>(value: Value) -> Result<L, ParseError<L::Err, ER>> {
+    parse_value(value, |position, error| ParseError::Left {
+        position,
+        error,
+    })
+}
+
+fn parse_right<R: FromStr, EL>(value: Value) -> Result<R, ParseError<EL, R::Err>> {
+    parse_value(value, |position, error| ParseError::Right {
+        position,
+        error,
+    })
+}
+
+impl<L: FromStr, R: FromStr> FromStr for EitherOf<L, R> {
+    type Err = ParseError<L::Err, R::Err>;
+
+    /// Parses `L(left)` or `R(right)`.
+    ///
+    /// # Examples
+    /// ```rust
+    /// use any_of::{EitherOf, Left};
+    ///
+    /// assert_eq!("L(42)".parse::<EitherOf<i32, bool>>(), Ok(Left(42)));
+    /// ```
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match split(s, "LR", "`L(` or `R(`")? {
+            Parts::Left(l) => parse_left(l).map(Left),
+            Parts::Right(r) => parse_right(r).map(Right),
+            Parts::Neither | Parts::Both(..) => unreachable!(),
+        }
+    }
+}
+
+impl<L: FromStr, R: FromStr> FromStr for BothOf<L, R> {
+    type Err = ParseError<L::Err, R::Err>;
+
+    /// Parses `B(left, right)`.
+    ///
+    /// # Examples
+    /// ```rust
+    /// use any_of::BothOf;
+    ///
+    /// assert_eq!("B(42, true)".parse::<BothOf<i32, bool>>(), Ok(BothOf::new(42, true)));
+    /// ```
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match split(s, "B", "`B(`")? {
+            Parts::Both(l, r) => Ok(BothOf::new(parse_left(l)?, parse_right(r)?)),
+            Parts::Neither | Parts::Left(_) | Parts::Right(_) => unreachable!(),
+        }
+    }
+}
+
+impl<L: FromStr, R: FromStr> FromStr for AnyOf<L, R> {
+    type Err = ParseError<L::Err, R::Err>;
+
+    /// Parses `_`, `L(left)`, `R(right)` or `B(left, right)`.
+    ///
+    /// # Examples
+    /// ```rust
+    /// use any_of::AnyOf;
+    ///
+    /// assert_eq!("_".parse::<AnyOf<i32, bool>>(), Ok(AnyOf::new_neither()));
+    /// assert_eq!("R(true)".parse::<AnyOf<i32, bool>>(), Ok(AnyOf::new_right(true)));
+    /// ```
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match split(s, "_LRB", "`_`, `L(`, `R(` or `B(`")? {
+            Parts::Neither => Ok(AnyOf::new_neither()),
+            Parts::Left(l) => parse_left(l).map(AnyOf::new_left),
+            Parts::Right(r) => parse_right(r).map(AnyOf::new_right),
+            Parts::Both(l, r) => Ok(AnyOf::new_both(parse_left(l)?, parse_right(r)?)),
+        }
+    }
+}
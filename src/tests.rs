@@ -6,3 +6,67 @@ mod test_both;
 mod test_any_of;
 
 mod test_any_of_x;
+
+mod test_validate;
+
+mod test_early_return;
+
+mod test_ordering;
+
+mod test_bitraversable;
+
+mod test_transpose;
+
+mod test_zip;
+
+mod test_algebra;
+
+mod test_entry;
+
+mod test_parse;
+
+mod test_codec;
+
+mod test_match_any;
+
+mod test_construct;
+
+#[cfg(feature = "futures")]
+mod test_future;
+
+mod test_packed;
+
+mod test_flat;
+
+mod test_builder;
+
+#[cfg(feature = "alloc")]
+mod test_any_of_vec;
+
+mod test_join;
+
+#[cfg(feature = "rayon")]
+mod test_parallel;
+
+#[cfg(feature = "serde")]
+mod test_serde;
+
+#[cfg(feature = "serde")]
+mod test_tristate;
+
+#[cfg(feature = "schemars")]
+mod test_schemars;
+
+#[cfg(feature = "defmt")]
+mod test_defmt;
+
+#[cfg(feature = "ufmt")]
+mod test_ufmt;
+
+mod test_layout;
+
+#[cfg(feature = "bytemuck")]
+mod test_bytemuck;
+
+#[cfg(feature = "zerocopy")]
+mod test_zerocopy;
@@ -0,0 +1,73 @@
+use crate::*;
+
+fn options(bits: u8, n: u8) -> [Option<u8>; 4] {
+    core::array::from_fn(|i| (i < n as usize && bits & (1 << i) != 0).then_some(i as u8))
+}
+
+#[test]
+fn test_either_assoc_round_trip() {
+    let values: [EitherOf<EitherOf<u8, char>, bool>; 3] =
+        [Left(Left(1)), Left(Right('b')), Right(true)];
+    for value in values {
+        assert_eq!(value.assoc_right().assoc_left(), value);
+    }
+    let values: [EitherOf<u8, EitherOf<char, bool>>; 3] =
+        [Left(1), Right(Left('b')), Right(Right(true))];
+    for value in values {
+        assert_eq!(value.assoc_left().assoc_right(), value);
+    }
+    assert_eq!(values[1].assoc_left(), Left(Right('b')));
+}
+
+#[test]
+fn test_any_of_assoc_round_trip() {
+    for bits in 0..8 {
+        let [a, b, c, _] = options(bits, 3);
+        let ab = AnyOf::new(a, b);
+        let left: AnyOf<AnyOf<u8, u8>, u8> = AnyOf::new((!ab.is_neither()).then_some(ab), c);
+        let right = left.assoc_right();
+        assert_eq!(right.left(), a.as_ref());
+        assert_eq!(right.right().and_then(|bc| bc.left()), b.as_ref());
+        assert_eq!(right.right().and_then(|bc| bc.right()), c.as_ref());
+        assert_eq!(right.assoc_left(), left);
+        assert_eq!(left.assoc_right().assoc_left().assoc_right(), right);
+    }
+}
+
+#[test]
+fn test_any_of_assoc_neither() {
+    let degenerate: AnyOf<AnyOf<u8, u8>, u8> = AnyOf::new_left(AnyOf::new_neither());
+    assert_eq!(degenerate.assoc_right(), AnyOf::new_neither());
+    let degenerate: AnyOf<AnyOf<u8, u8>, u8> = AnyOf::new_both(AnyOf::new_neither(), 3);
+    assert_eq!(
+        degenerate.assoc_right(),
+        AnyOf::new_right(AnyOf::new_right(3))
+    );
+}
+
+#[test]
+fn test_distribute_round_trip() {
+    let values: [BothOf<EitherOf<u8, char>, bool>; 2] =
+        [BothOf::new(Left(1), true), BothOf::new(Right('b'), false)];
+    for value in values {
+        assert_eq!(value.distribute().factor(), value);
+    }
+    assert_eq!(values[0].distribute(), Left(BothOf::new(1, true)));
+    let factored: EitherOf<BothOf<u8, bool>, BothOf<char, bool>> = Right(BothOf::new('b', true));
+    assert_eq!(factored.factor().distribute(), factored);
+}
+
+#[test]
+fn test_balanced_round_trip() {
+    for bits in 0..16 {
+        let [a, b, c, d] = options(bits, 4);
+        let balanced: AnyOf4<u8> = AnyOf4::new4(a, b, c, d);
+        let leaning = balanced.into_right_leaning();
+        assert_eq!(leaning.left(), a.as_ref());
+        assert_eq!(leaning.into_balanced(), balanced);
+        assert_eq!(
+            leaning.into_balanced().opt4(),
+            (a.as_ref(), b.as_ref(), c.as_ref(), d.as_ref())
+        );
+    }
+}
@@ -239,3 +239,139 @@ fn test_with_left_and_with_right() {
     let both_values = both.both_or_else(|| BothOf::new(0, "Default"));
     assert_eq!(both_values, BothOf::new(42, "Hello"));
 }
+
+#[test]
+fn test_take() {
+    let mut any: AnyOf<i32, &str> = AnyOf::new_both(1, "one");
+    assert_eq!(any.take(), AnyOf::new_both(1, "one"));
+    assert!(any.is_neither());
+
+    let mut any: AnyOf<i32, &str> = AnyOf::new_both(1, "one");
+    assert_eq!(any.take_right(), Some("one"));
+    assert_eq!(any, AnyOf::new_left(1));
+    assert_eq!(any.take_right(), None);
+    assert_eq!(any.take_left(), Some(1));
+    assert!(any.is_neither());
+    assert_eq!(any.take_left(), None);
+}
+
+#[test]
+fn test_replace() {
+    let mut any: AnyOf<i32, &str> = AnyOf::new_neither();
+    assert_eq!(any.replace_right("one"), None);
+    assert_eq!(any, AnyOf::new_right("one"));
+    assert_eq!(any.replace_left(1), None);
+    assert_eq!(any, AnyOf::new_both(1, "one"));
+    assert_eq!(any.replace_right("two"), Some("one"));
+    assert_eq!(any.replace_left(2), Some(1));
+    assert_eq!(any, AnyOf::new_both(2, "two"));
+}
+
+#[test]
+fn test_insert_and_get_or_insert() {
+    let mut any: AnyOf<i32, i32> = AnyOf::new_neither();
+    *any.insert_left(1) += 10;
+    assert_eq!(any, AnyOf::new_left(11));
+    *any.insert_right(2) += 20;
+    assert_eq!(any, AnyOf::new_both(11, 22));
+    assert_eq!(*any.insert_left(3), 3);
+
+    let mut any: AnyOf<i32, i32> = AnyOf::new_left(1);
+    assert_eq!(*any.get_or_insert_left_with(|| unreachable!()), 1);
+    *any.get_or_insert_right_with(|| 2) *= 10;
+    assert_eq!(any, AnyOf::new_both(1, 20));
+    assert_eq!(*any.get_or_insert_right_with(|| 0), 20);
+}
+
+#[test]
+fn test_display() {
+    use std::string::ToString;
+
+    assert_eq!(AnyOf::<i32, &str>::new_neither().to_string(), "_");
+    assert_eq!(AnyOf::<i32, &str>::new_left(42).to_string(), "L(42)");
+    assert_eq!(AnyOf::<i32, &str>::new_right("Hello").to_string(), "R(Hello)");
+    assert_eq!(AnyOf::new_both(42, "Hello").to_string(), "B(42, Hello)");
+}
+
+#[derive(Debug)]
+struct Inner;
+
+impl core::fmt::Display for Inner {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        f.write_str("inner")
+    }
+}
+
+impl core::error::Error for Inner {}
+
+#[derive(Debug)]
+struct Outer;
+
+impl core::fmt::Display for Outer {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        f.write_str("outer")
+    }
+}
+
+impl core::error::Error for Outer {
+    fn source(&self) -> Option<&(dyn core::error::Error + 'static)> {
+        Some(&Inner)
+    }
+}
+
+#[test]
+fn test_error() {
+    use core::error::Error;
+    use std::string::ToString;
+
+    let neither: AnyOf<Inner, Outer> = AnyOf::new_neither();
+    assert!(neither.source().is_none());
+
+    let right: AnyOf<Inner, Outer> = AnyOf::new_right(Outer);
+    assert_eq!(right.source().unwrap().to_string(), "inner");
+
+    let both: AnyOf<Inner, Outer> = AnyOf::new_both(Inner, Outer);
+    assert_eq!(both.to_string(), "B(inner, outer)");
+    assert_eq!(both.source().unwrap().to_string(), "inner");
+}
+
+/// An error wrapping its cause.
+#[derive(Debug)]
+struct Wrap<E>(E);
+
+impl<E> core::fmt::Display for Wrap<E> {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        f.write_str("wrap")
+    }
+}
+
+impl<E: core::error::Error + 'static> core::error::Error for Wrap<E> {
+    fn source(&self) -> Option<&(dyn core::error::Error + 'static)> {
+        Some(&self.0)
+    }
+}
+
+#[test]
+fn test_error_source_downcast() {
+    use core::error::Error;
+
+    let both = BothOf::new(Wrap(Wrap(Inner)), Wrap(Outer));
+    let source = both.source().unwrap();
+    assert!(source.is::<Wrap<Inner>>());
+    let source = source.source().unwrap();
+    assert!(source.is::<Inner>());
+    assert!(source.source().is_none());
+
+    let right = both.right_source().unwrap();
+    assert!(right.is::<Outer>());
+    let right = right.source().unwrap();
+    assert!(right.is::<Inner>());
+    assert!(right.source().is_none());
+
+    // The same causes as for an `EitherOf` holding one of the errors.
+    let left: EitherOf<_, Outer> = Left(Wrap(Wrap(Inner)));
+    assert!(left.source().unwrap().is::<Wrap<Inner>>());
+    let both = AnyOf::new_both(Inner, Wrap(Outer));
+    assert!(both.source().unwrap().is::<Outer>());
+    assert!(both.source().unwrap().source().unwrap().is::<Inner>());
+}
@@ -0,0 +1,86 @@
+use crate::any_of_vec::AnyOfVec;
+use crate::*;
+use std::rc::Rc;
+use std::vec;
+use std::vec::Vec;
+
+fn sample(len: usize) -> Vec<AnyOf<usize, i64>> {
+    (0..len)
+        .map(|i| match i % 4 {
+            0 => AnyOf::new_neither(),
+            1 => AnyOf::new_left(i),
+            2 => AnyOf::new_right(-(i as i64)),
+            _ => AnyOf::new_both(i, -(i as i64)),
+        })
+        .collect()
+}
+
+#[test]
+fn test_any_of_vec_push_get_pop() {
+    let expected = sample(150);
+    let mut values = AnyOfVec::new();
+    for value in expected.iter() {
+        values.push(*value);
+    }
+    assert_eq!(values.len(), 150);
+    for (i, value) in expected.iter().enumerate() {
+        assert_eq!(values.get(i), Some(AnyOf::new(value.left(), value.right())));
+    }
+    assert_eq!(values.get(150), None);
+    assert_eq!(values.lefts().len(), 75);
+    assert_eq!(values.rights().len(), 74);
+
+    for value in expected.iter().rev() {
+        assert_eq!(values.pop(), Some(*value));
+    }
+    assert_eq!(values.pop(), None);
+    assert!(values.is_empty());
+    assert_eq!(values, AnyOfVec::new());
+}
+
+#[test]
+fn test_any_of_vec_iter() {
+    let expected = sample(130);
+    let values: AnyOfVec<usize, i64> = expected.iter().copied().collect();
+    assert_eq!(values.iter().len(), 130);
+    assert!(
+        values
+            .iter()
+            .eq(expected.iter().map(|v| AnyOf::new(v.left(), v.right())))
+    );
+    assert_eq!(values.clone().into_iter().collect::<Vec<_>>(), expected);
+    assert_eq!(Vec::from(values.clone()), expected);
+    assert_eq!(AnyOfVec::from(expected), values);
+}
+
+#[test]
+fn test_any_of_vec_shape() {
+    let values: AnyOfVec<usize, i64> = AnyOfVec::from(sample(8));
+    assert_eq!(values.shape(0), Some(0b00));
+    assert_eq!(values.shape(1), Some(0b01));
+    assert_eq!(values.shape(2), Some(0b10));
+    assert_eq!(values.shape(3), Some(0b11));
+    assert_eq!(values.shape(8), None);
+    assert_eq!(values.positions(0b11).collect::<Vec<_>>(), vec![3, 7]);
+    assert_eq!(
+        values.filter_shape(0b01).collect::<Vec<_>>(),
+        vec![AnyOf::new_left(&1), AnyOf::new_left(&5)]
+    );
+    assert_eq!(values.filter_shape(0b00).count(), 2);
+}
+
+#[test]
+fn test_any_of_vec_drop() {
+    let value = Rc::new(0);
+    let mut values: AnyOfVec<Rc<i32>> = AnyOfVec::new();
+    values.push(AnyOf::new_both(value.clone(), value.clone()));
+    values.push(AnyOf::new_neither());
+    values.push(AnyOf::new_right(value.clone()));
+    assert_eq!(Rc::strong_count(&value), 4);
+    let mut iter = values.clone().into_iter();
+    assert_eq!(Rc::strong_count(&value), 7);
+    iter.next();
+    drop(iter);
+    values.clear();
+    assert_eq!(Rc::strong_count(&value), 1);
+}
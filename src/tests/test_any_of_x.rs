@@ -1,3 +1,5 @@
+#![allow(clippy::type_complexity)]
+
 use crate::*;
 
 #[test]
@@ -61,3 +63,17 @@ fn test_anyof16_constructors() {
     assert_eq!(via_new.llll(), Some(&1));
     assert_eq!(via_from, via_new);
 }
+
+#[test]
+fn test_into_opt() {
+    let value: AnyOf4<i32> = AnyOf4::new4(Some(1), None, Some(3), None);
+    assert_eq!(value.into_opt4(), (Some(1), None, Some(3), None));
+
+    let value: AnyOf8<i32> = AnyOf8::new8(None, Some(2), None, None, None, None, None, Some(8));
+    assert_eq!(value.into_opt8(), (None, Some(2), None, None, None, None, None, Some(8)));
+
+    let value: AnyOf16<i32> = AnyOf16::new_right(AnyOf8::new_left(AnyOf4::new_right(AnyOf::new_left(12))));
+    let opt = value.into_opt16();
+    assert_eq!(opt.10, Some(12));
+    assert_eq!(AnyOf16::from_opt16(opt), value);
+}
@@ -0,0 +1,94 @@
+use crate::*;
+use std::string::String;
+use std::vec;
+use std::vec::Vec;
+
+fn sum(value: impl Bifoldable<u32, u32>) -> u32 {
+    value.bifold(0, |acc, l| acc + l, |acc, r| acc + r)
+}
+
+fn parse<T: Bitraversable<&'static str, &'static str>>(value: T) -> Option<T::Output<u32, u32>> {
+    value.traverse_option(|l| l.parse().ok(), |r| r.parse().ok())
+}
+
+#[test]
+fn test_bifold() {
+    assert_eq!(sum(AnyOf::new_neither()), 0);
+    assert_eq!(sum(AnyOf::new_left(1)), 1);
+    assert_eq!(sum(AnyOf::new_both(1, 2)), 3);
+    assert_eq!(sum(EitherOf::<u32, u32>::Right(2)), 2);
+    assert_eq!(sum(BothOf::new(1, 2)), 3);
+
+    let order = BothOf::new('l', 'r').bifold(
+        String::new(),
+        |mut s, l| {
+            s.push(l);
+            s
+        },
+        |mut s, r| {
+            s.push(r);
+            s
+        },
+    );
+    assert_eq!(order, "lr");
+}
+
+#[derive(Debug, PartialEq)]
+struct Digits(u32);
+
+impl Semigroup for Digits {
+    fn append(self, other: Self) -> Self {
+        Digits(self.0 * 10 + other.0)
+    }
+}
+
+#[test]
+fn test_bifold_map() {
+    let both: AnyOf<u32, u32> = AnyOf::new_both(1, 2);
+    assert_eq!(both.bifold_map(Digits, |r| Digits(r * 2)), Some(Digits(14)));
+    assert_eq!(
+        AnyOf::<u32, u32>::new_neither().bifold_map(Digits, Digits),
+        None
+    );
+    assert_eq!(both.bicount(), 2);
+    assert_eq!(EitherOf::<u8, u8>::Left(1).bicount(), 1);
+}
+
+#[test]
+fn test_bifold_aliases() {
+    let value: AnyOf4<u32> = AnyOf4::new4(Some(1), None, Some(3), Some(4));
+    let total = value.bifold(0, |acc, l| acc + sum(l), |acc, r| acc + sum(r));
+    assert_eq!(total, 8);
+    assert_eq!(value.bicount(), 2);
+}
+
+#[test]
+fn test_traverse_option() {
+    assert_eq!(
+        parse(AnyOf::new_both("1", "2")),
+        Some(AnyOf::new_both(1, 2))
+    );
+    assert_eq!(parse(AnyOf::new_both("1", "x")), None);
+    assert_eq!(parse(AnyOf::new_neither()), Some(AnyOf::new_neither()));
+    assert_eq!(parse(EitherOf::<&str, &str>::Left("3")), Some(Left(3)));
+    assert_eq!(parse(BothOf::new("x", "2")), None);
+    assert_eq!(parse(BothOf::new("1", "2")), Some(BothOf::new(1, 2)));
+}
+
+#[test]
+fn test_traverse_result() {
+    let mut calls = Vec::new();
+    let result: Result<BothOf<u8, u8>, &str> = BothOf::new(1, 2).traverse_result(
+        |_| {
+            calls.push('l');
+            Err::<u8, _>("left")
+        },
+        Ok,
+    );
+    assert_eq!(result, Err("left"));
+    assert_eq!(calls, vec!['l']);
+
+    let value: AnyOf4<u8> = AnyOf4::new_left(AnyOf::new_right(2));
+    let result: Result<_, ()> = value.traverse_result(|l| Ok(l.map_right(|r| r * 2)), Ok);
+    assert_eq!(result, Ok(AnyOf4::new_left(AnyOf::new_right(4))));
+}
@@ -51,3 +51,11 @@ fn test_both_map() {
     assert_eq!(mapped.left, 20);
     assert!(mapped.right < 1.6);
 }
+
+#[test]
+fn test_both_display() {
+    use std::string::ToString;
+
+    let both = BothOf::new(10, "right");
+    assert_eq!(both.to_string(), "B(10, right)");
+}
@@ -0,0 +1,69 @@
+use crate::builder::{AnyOf8Builder, AnyOf16Builder};
+use crate::*;
+
+#[test]
+fn test_any_of8_builder() {
+    let value: AnyOf8<i32> = AnyOf8::builder8().llr(2).rrl(7).build();
+    assert_eq!(
+        value,
+        AnyOf8::new8(None, Some(2), None, None, None, None, Some(7), None)
+    );
+
+    let empty: AnyOf8<i32> = AnyOf8Builder::default().build();
+    assert!(empty.is_neither());
+
+    let left_only: AnyOf8<i32> = AnyOf8::builder8().lrr(4).build();
+    assert!(left_only.is_left());
+
+    let overwritten: AnyOf8<i32> = AnyOf8::builder8().lll(1).lll(2).into();
+    assert_eq!(overwritten.lll(), Some(&2));
+}
+
+#[test]
+fn test_any_of16_builder() {
+    let value: AnyOf16<i32, char> = AnyOf16::builder16().lllr('b').rlll(10).build();
+    assert_eq!(value.lllr(), Some(&'b'));
+    assert_eq!(value.rlll(), Some(&10));
+    assert_eq!(value.into_opt16().0, None);
+
+    let opt = AnyOf16::<u8>::new16(
+        Some(0),
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        Some(15),
+    )
+    .into_opt16();
+    let rebuilt: AnyOf16<u8> = AnyOf16Builder::from_opt(opt).build();
+    assert_eq!(rebuilt, AnyOf16::builder16().llll(0).rrrr(15).build());
+}
+
+leaf_aliases! {
+    trait Settings for AnyOf8Builder<u64, bool> {
+        timeout: u64 = lll,
+        verbose: bool = llr,
+        retries: bool = rrr,
+    }
+}
+
+#[test]
+fn test_leaf_aliases() {
+    let settings: AnyOf8<u64, bool> = AnyOf8::builder8().timeout(30).retries(true).build();
+    assert_eq!(settings.lll(), Some(&30));
+    assert_eq!(settings.rrr(), Some(&true));
+    assert_eq!(settings.llr(), None);
+
+    let verbose: AnyOf8<u64, bool> = AnyOf8Builder::new().verbose(false).build();
+    assert_eq!(verbose.into_opt8().1, Some(false));
+}
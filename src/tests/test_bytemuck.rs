@@ -0,0 +1,22 @@
+use crate::ffi::FfiEitherOf;
+use crate::*;
+use bytemuck::Zeroable;
+
+#[test]
+fn test_both_of_pod() {
+    let values = [BothOf::new(1u32, 2u32), BothOf::new(3, 4)];
+    let bytes: &[u8] = bytemuck::cast_slice(&values);
+    assert_eq!(bytes.len(), 16);
+    assert_eq!(&bytes[4..8], &2u32.to_ne_bytes());
+
+    let words: &[u32] = bytemuck::cast_slice(&values);
+    assert_eq!(words, &[1, 2, 3, 4]);
+    let back: &[BothOf<u32>] = bytemuck::cast_slice(words);
+    assert_eq!(back, &values);
+}
+
+#[test]
+fn test_zeroable() {
+    assert_eq!(BothOf::<u8, u64>::zeroed(), BothOf::new(0, 0));
+    assert_eq!(FfiEitherOf::<u32, char>::zeroed(), FfiEitherOf::Left(0));
+}
@@ -0,0 +1,167 @@
+use crate::codec::{CodecError, Decode, Encode};
+use crate::*;
+use core::fmt::Debug;
+
+fn round_trip<'a, T>(value: T, buf: &'a mut [u8]) -> &'a [u8]
+where
+    T: Encode + Decode<'a> + PartialEq + Debug,
+{
+    let len = value.encode(buf).unwrap();
+    assert_eq!(len, value.encoded_len());
+    let bytes = &buf[..len];
+    assert_eq!(T::decode(bytes), Ok((value, len)));
+    bytes
+}
+
+#[test]
+fn test_primitives() {
+    let mut buf = [0; 32];
+    assert_eq!(round_trip(0x1234u16, &mut buf), &[0x34, 0x12]);
+    assert_eq!(round_trip(-1i32, &mut buf), &[0xff; 4]);
+    assert_eq!(round_trip(u128::MAX, &mut buf).len(), 16);
+    assert_eq!(round_trip(true, &mut buf), &[1]);
+    assert_eq!(bool::decode(&[2]), Err(CodecError::InvalidBool(2)));
+    assert_eq!(u32::decode(&[1, 2, 3]), Err(CodecError::UnexpectedEnd));
+    assert_eq!(7u64.encode(&mut [0; 4]), Err(CodecError::BufferTooSmall));
+}
+
+#[test]
+fn test_byte_slices() {
+    let mut buf = [0; 300];
+    let bytes: &[u8] = b"abc";
+    assert_eq!(round_trip(bytes, &mut buf), b"\x03abc");
+
+    let long = [7; 200];
+    let encoded = round_trip(&long[..], &mut buf);
+    assert_eq!(&encoded[..2], &[0xc8, 0x01]);
+    assert_eq!(encoded.len(), 202);
+
+    assert_eq!(<&[u8]>::decode(b"\x03ab"), Err(CodecError::UnexpectedEnd));
+    assert_eq!(<&[u8]>::decode(&[0x80]), Err(CodecError::UnexpectedEnd));
+    assert_eq!(
+        <&[u8]>::decode(&[0xff; 11]),
+        Err(CodecError::LengthOverflow)
+    );
+}
+
+#[test]
+fn test_either_and_both() {
+    let mut buf = [0; 16];
+    let left: EitherOf<u8, u16> = Left(1);
+    assert_eq!(round_trip(left, &mut buf), &[0b01, 1]);
+    let right: EitherOf<u8, u16> = Right(2);
+    assert_eq!(round_trip(right, &mut buf), &[0b10, 2, 0]);
+    assert_eq!(
+        EitherOf::<u8, u8>::decode(&[0b11, 0]),
+        Err(CodecError::InvalidShape(0b11))
+    );
+    assert_eq!(round_trip(BothOf::new(1u8, true), &mut buf), &[1, 1]);
+}
+
+#[test]
+fn test_any_of() {
+    let mut buf = [0; 16];
+    assert_eq!(round_trip(AnyOf::<u8, u16>::new_neither(), &mut buf), &[0]);
+    assert_eq!(
+        round_trip(AnyOf::<u8, u16>::new_left(1), &mut buf),
+        &[0b01, 1]
+    );
+    assert_eq!(
+        round_trip(AnyOf::<u8, u16>::new_right(2), &mut buf),
+        &[0b10, 2, 0]
+    );
+    assert_eq!(
+        round_trip(AnyOf::<u8, u16>::new_both(1, 2), &mut buf),
+        &[0b11, 1, 2, 0]
+    );
+    assert_eq!(
+        AnyOf::<u8, u8>::decode(&[0b100]),
+        Err(CodecError::InvalidShape(0b100))
+    );
+    assert_eq!(
+        AnyOf::<u8, u8>::decode(&[0b11, 1]),
+        Err(CodecError::UnexpectedEnd)
+    );
+}
+
+#[test]
+fn test_nested_headers() {
+    let mut buf = [0; 64];
+    for bits in 0..16u8 {
+        let leaf = |i: u8| (bits & (1 << i) != 0).then_some(i);
+        let any4: AnyOf4<u8> = AnyOf4::new4(leaf(0), leaf(1), leaf(2), leaf(3));
+        let encoded = round_trip(any4, &mut buf);
+        assert_eq!(encoded[0], bits);
+        assert_eq!(encoded.len(), 1 + bits.count_ones() as usize);
+    }
+
+    let any8: AnyOf8<u8> = AnyOf8::new_both(
+        AnyOf4::new_left(AnyOf::new_left(1)),
+        AnyOf4::new_right(AnyOf::new_right(8)),
+    );
+    assert_eq!(round_trip(any8, &mut buf), &[0b1000_0001, 1, 8]);
+
+    let any16: AnyOf16<u8> = AnyOf16::from_opt16((
+        Some(0),
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        Some(15),
+    ));
+    assert_eq!(
+        round_trip(any16, &mut buf),
+        &[0b0000_0001, 0b1000_0000, 0, 15]
+    );
+}
+
+#[test]
+fn test_leaf_limit() {
+    type AnyOf32 = AnyOf<AnyOf16<u8>, AnyOf16<u8>>;
+
+    let mut buf = [0; 16];
+    let first_and_last = AnyOf32::new_both(
+        AnyOf16::new_left(AnyOf8::new_left(AnyOf4::new_left(AnyOf::new_left(0)))),
+        AnyOf16::new_right(AnyOf8::new_right(AnyOf4::new_right(AnyOf::new_right(31)))),
+    );
+    assert_eq!(first_and_last.shape(), 1 << 31 | 1);
+    assert_eq!(
+        round_trip(first_and_last, &mut buf),
+        &[1, 0, 0, 0b1000_0000, 0, 31]
+    );
+    let last = AnyOf32::new_right(AnyOf16::new_right(AnyOf8::new_right(AnyOf4::new_right(
+        AnyOf::new_right(31),
+    ))));
+    assert_eq!(round_trip(last, &mut buf), &[0, 0, 0, 0b1000_0000, 31]);
+}
+
+#[test]
+fn test_nested_neither() {
+    let mut buf = [0; 8];
+    let nested: AnyOf<AnyOf<u8, u8>, u8> = AnyOf::new_both(AnyOf::new_neither(), 3);
+    let len = nested.encode(&mut buf).unwrap();
+    assert_eq!(&buf[..len], &[0b100, 3]);
+    let decoded = AnyOf::<AnyOf<u8, u8>, u8>::decode(&buf[..len]);
+    assert_eq!(decoded, Ok((AnyOf::new_right(3), len)));
+}
+
+#[test]
+fn test_mixed_payloads() {
+    let mut buf = [0; 32];
+    let value: AnyOf<EitherOf<u8, bool>, BothOf<&[u8], i16>> =
+        AnyOf::new_both(Right(true), BothOf::new(b"hi", -2));
+    assert_eq!(
+        round_trip(value, &mut buf),
+        &[0b11, 0b10, 1, 2, b'h', b'i', 0xfe, 0xff]
+    );
+}
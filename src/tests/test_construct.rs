@@ -0,0 +1,65 @@
+use crate::*;
+
+#[test]
+fn test_any_of_macro_sizes() {
+    let neither: AnyOf<i32> = any_of!();
+    assert!(neither.is_neither());
+
+    let any: AnyOf<i32> = any_of!(Some(1));
+    assert_eq!(any, AnyOf::new_left(1));
+    let any: AnyOf<i32, char> = any_of!(None, Some('a'),);
+    assert_eq!(any, AnyOf::new_right('a'));
+
+    let any4: AnyOf4<i32> = any_of!(Some(1), Some(2), Some(3));
+    assert_eq!(any4.into_opt4(), (Some(1), Some(2), Some(3), None));
+    let any4: AnyOf4<i32> = any_of!(None, None, None, Some(4));
+    assert_eq!(any4, AnyOf4::new4(None, None, None, Some(4)));
+
+    let any8: AnyOf8<i32> = any_of!(Some(1), None, None, None, None, None, Some(7));
+    assert_eq!(
+        any8,
+        AnyOf8::new8(Some(1), None, None, None, None, None, Some(7), None)
+    );
+
+    let any16: AnyOf16<i32> = any_of!(None, None, None, None, None, None, None, None, Some(9));
+    let leaves = any16.into_opt16();
+    assert_eq!(leaves.8, Some(9));
+    assert_eq!(leaves.15, None);
+
+    let any16: AnyOf16<u8> = any_of!(
+        Some(0),
+        Some(1),
+        Some(2),
+        Some(3),
+        Some(4),
+        Some(5),
+        Some(6),
+        Some(7),
+        Some(8),
+        Some(9),
+        Some(10),
+        Some(11),
+        Some(12),
+        Some(13),
+        Some(14),
+        Some(15),
+    );
+    assert_eq!(any16.into_opt16().15, Some(15));
+}
+
+#[test]
+fn test_any_of_macro_heterogeneous() {
+    let any4: AnyOf4<i32, char, bool, &str> = any_of!(Some(1), None, Some(true));
+    assert_eq!(any4, AnyOf4::new4(Some(1), None, Some(true), None));
+}
+
+#[test]
+fn test_side_macros() {
+    assert_eq!(any_of_both!(1, 'a'), AnyOf::new_both(1, 'a'));
+    let left: AnyOf<i32, char> = any_of_left!(1);
+    assert_eq!(left, AnyOf::new_left(1));
+    let right: AnyOf<i32, char> = any_of_right!('a');
+    assert_eq!(right, AnyOf::new_right('a'));
+    let nested: AnyOf4<i32> = any_of_both!(any_of_left!(1), any_of_right!(4));
+    assert_eq!(nested, any_of!(Some(1), None, None, Some(4)));
+}
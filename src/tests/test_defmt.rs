@@ -0,0 +1,14 @@
+use crate::*;
+
+fn assert_format<T: defmt::Format>() {}
+
+#[test]
+fn test_defmt_format() {
+    assert_format::<EitherOf<i32, bool>>();
+    assert_format::<BothOf<u8, &str>>();
+    assert_format::<AnyOf<i32, bool>>();
+    assert_format::<AnyOf4<u8, u16, u32, u64>>();
+    assert_format::<AnyOf8<i32>>();
+    assert_format::<AnyOf16<u8>>();
+    assert_format::<AnyOf<EitherOf<u8, i8>, BothOf<u16, &str>>>();
+}
@@ -0,0 +1,77 @@
+use crate::*;
+
+#[test]
+fn test_either_ok_or() {
+    let left: EitherOf<i32, &str> = Left(1);
+    let right: EitherOf<i32, &str> = Right("one");
+    assert_eq!(left.ok_or_left(), Err(1));
+    assert_eq!(right.ok_or_left(), Ok("one"));
+    assert_eq!(left.ok_or_right(), Ok(1));
+    assert_eq!(right.ok_or_right(), Err("one"));
+}
+
+#[test]
+fn test_any_of_ok_or() {
+    let neither: AnyOf<i32, &str> = AnyOf::new_neither();
+    let left: AnyOf<i32, &str> = AnyOf::new_left(1);
+    let right: AnyOf<i32, &str> = AnyOf::new_right("one");
+    let both = AnyOf::new_both(1, "one");
+    assert_eq!(neither.ok_or_left(), Err(None));
+    assert_eq!(left.ok_or_left(), Err(Some(1)));
+    assert_eq!(right.ok_or_left(), Ok("one"));
+    assert_eq!(both.ok_or_left(), Ok("one"));
+    assert_eq!(neither.ok_or_right(), Err(None));
+    assert_eq!(left.ok_or_right(), Ok(1));
+    assert_eq!(right.ok_or_right(), Err(Some("one")));
+    assert_eq!(both.ok_or_right(), Ok(1));
+}
+
+#[test]
+fn test_right_or_return() {
+    fn increment(value: EitherOf<u8, i32>) -> EitherOf<u16, i64> {
+        Right(i64::from(right_or_return!(value)) + 1)
+    }
+    assert_eq!(increment(Right(1)), Right(2));
+    assert_eq!(increment(Left(1)), Left(1u16));
+
+    fn double(value: AnyOf<u8, i32>) -> AnyOf<u16, i32> {
+        AnyOf::new_right(right_or_return!(value) * 2)
+    }
+    assert_eq!(double(AnyOf::new_right(2)), AnyOf::new_right(4));
+    assert_eq!(double(AnyOf::new_both(1, 2)), AnyOf::new_right(4));
+    assert_eq!(double(AnyOf::new_left(1)), AnyOf::new_left(1u16));
+    assert_eq!(double(AnyOf::new_neither()), AnyOf::new_neither());
+}
+
+#[test]
+fn test_left_or_return() {
+    fn increment(value: EitherOf<i32, u8>) -> EitherOf<i32, u16> {
+        Left(left_or_return!(value) + 1)
+    }
+    assert_eq!(increment(Left(1)), Left(2));
+    assert_eq!(increment(Right(1)), Right(1u16));
+
+    fn double(value: AnyOf<i32, u8>) -> AnyOf<i32, u16> {
+        AnyOf::new_left(left_or_return!(value) * 2)
+    }
+    assert_eq!(double(AnyOf::new_both(2, 1)), AnyOf::new_left(4));
+    assert_eq!(double(AnyOf::new_right(1)), AnyOf::new_right(1u16));
+    assert_eq!(double(AnyOf::new_neither()), AnyOf::new_neither());
+}
+
+#[cfg(feature = "try_trait")]
+#[test]
+fn test_try_operator() {
+    fn either(value: EitherOf<u8, i32>) -> EitherOf<u16, i32> {
+        Right(value? + 1)
+    }
+    assert_eq!(either(Right(1)), Right(2));
+    assert_eq!(either(Left(1)), Left(1));
+
+    fn any_of(value: AnyOf<u8, i32>, other: EitherOf<u8, i32>) -> AnyOf<u16, i32> {
+        AnyOf::new_right(value? + other?)
+    }
+    assert_eq!(any_of(AnyOf::new_both(1, 2), Right(3)), AnyOf::new_right(5));
+    assert_eq!(any_of(AnyOf::new_neither(), Right(3)), AnyOf::new_neither());
+    assert_eq!(any_of(AnyOf::new_right(2), Left(3)), AnyOf::new_left(3));
+}
@@ -148,4 +148,62 @@ fn test_unwrap_right() {
 fn test_unwrap_right_panics() {
     let either: EitherOf<i32, &str> = EitherOf::new_left(101);
     either.unwrap_right();
-}
\ No newline at end of file
+}
+
+#[derive(Debug)]
+struct Cause;
+
+impl core::fmt::Display for Cause {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        f.write_str("cause")
+    }
+}
+
+impl core::error::Error for Cause {}
+
+#[derive(Debug)]
+struct Failure(Cause);
+
+impl core::fmt::Display for Failure {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        f.write_str("failure")
+    }
+}
+
+impl core::error::Error for Failure {
+    fn source(&self) -> Option<&(dyn core::error::Error + 'static)> {
+        Some(&self.0)
+    }
+}
+
+#[test]
+fn test_display() {
+    use std::string::ToString;
+
+    let either: EitherOf<i32, &str> = EitherOf::new_left(42);
+    assert_eq!(either.to_string(), "L(42)");
+    let either: EitherOf<i32, &str> = EitherOf::new_right("Hello");
+    assert_eq!(either.to_string(), "R(Hello)");
+}
+
+#[test]
+fn test_error_source() {
+    use core::error::Error;
+    use std::string::ToString;
+
+    let either: EitherOf<Failure, Cause> = Left(Failure(Cause));
+    assert_eq!(either.to_string(), "L(failure)");
+    assert_eq!(either.source().unwrap().to_string(), "cause");
+
+    let either: EitherOf<Failure, Cause> = Right(Cause);
+    assert!(either.source().is_none());
+
+    fn fallible(fail: bool) -> Result<(), EitherOf<Failure, Cause>> {
+        let parsed: Result<(), Failure> = if fail { Err(Failure(Cause)) } else { Ok(()) };
+        parsed.map_err(Left)?;
+        Ok(())
+    }
+    let boxed: Result<(), std::boxed::Box<dyn Error>> = fallible(true).map_err(Into::into);
+    assert_eq!(boxed.unwrap_err().to_string(), "L(failure)");
+    assert!(fallible(false).is_ok());
+}
@@ -0,0 +1,72 @@
+use crate::entry::{LeftEntry, RightEntry};
+use crate::*;
+
+#[test]
+fn test_entry_variants() {
+    let mut any: AnyOf<i32, &str> = AnyOf::new_left(1);
+    assert!(any.entry_left().is_occupied());
+    assert!(!any.entry_right().is_occupied());
+    assert!(matches!(any.entry_right(), RightEntry::Vacant(_)));
+}
+
+#[test]
+fn test_or_insert() {
+    let mut any: AnyOf<i32, &str> = AnyOf::new_neither();
+    assert_eq!(*any.entry_right().or_insert("one"), "one");
+    assert_eq!(any, AnyOf::new_right("one"));
+    assert_eq!(*any.entry_right().or_insert("two"), "one");
+    assert_eq!(*any.entry_left().or_insert_with(|| 1), 1);
+    assert_eq!(*any.entry_left().or_default(), 1);
+    assert_eq!(any, AnyOf::new_both(1, "one"));
+
+    let mut any: AnyOf<i32, i32> = AnyOf::new_right(5);
+    *any.entry_left().or_default() += 2;
+    assert_eq!(any, AnyOf::new_both(2, 5));
+}
+
+#[test]
+fn test_and_modify() {
+    let mut any: AnyOf<i32, i32> = AnyOf::new_right(5);
+    any.entry_left().and_modify(|l| *l += 1);
+    assert_eq!(any, AnyOf::new_right(5));
+    any.entry_right().and_modify(|r| *r += 1).or_insert(0);
+    assert_eq!(any, AnyOf::new_right(6));
+    any.entry_left().and_modify(|l| *l += 1).or_insert(0);
+    assert_eq!(any, AnyOf::new_both(0, 6));
+}
+
+#[test]
+fn test_occupied() {
+    let mut any: AnyOf<i32, &str> = AnyOf::new_both(1, "one");
+    if let LeftEntry::Occupied(mut occupied) = any.entry_left() {
+        assert_eq!(*occupied.get(), 1);
+        *occupied.get_mut() += 1;
+        assert_eq!(occupied.insert(10), 2);
+        *occupied.into_mut() += 1;
+    } else {
+        panic!("the left side is occupied");
+    }
+    assert_eq!(any, AnyOf::new_both(11, "one"));
+
+    let RightEntry::Occupied(occupied) = any.entry_right() else {
+        panic!("the right side is occupied");
+    };
+    assert_eq!(occupied.remove(), "one");
+    assert_eq!(any, AnyOf::new_left(11));
+
+    let LeftEntry::Occupied(occupied) = any.entry_left() else {
+        panic!("the left side is occupied");
+    };
+    assert_eq!(occupied.remove(), 11);
+    assert!(any.is_neither());
+}
+
+#[test]
+fn test_vacant() {
+    let mut any: AnyOf<i32, &str> = AnyOf::new_left(1);
+    let RightEntry::Vacant(vacant) = any.entry_right() else {
+        panic!("the right side is vacant");
+    };
+    *vacant.insert("one") = "two";
+    assert_eq!(any, AnyOf::new_both(1, "two"));
+}
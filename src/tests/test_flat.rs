@@ -0,0 +1,129 @@
+#![allow(clippy::type_complexity)]
+
+use crate::*;
+use core::mem::size_of;
+use std::rc::Rc;
+
+#[test]
+fn test_flat8_leaves() {
+    let flat: FlatAnyOf8<i32> =
+        FlatAnyOf8::from_opt8((Some(1), None, None, Some(4), None, None, None, Some(8)));
+    assert_eq!(flat.shape(), 0b1000_1001);
+    assert_eq!(flat.presence_count(), 3);
+    assert!(!flat.is_empty());
+    assert_eq!(flat.lll(), Some(&1));
+    assert_eq!(flat.llr(), None);
+    assert_eq!(flat.lrr(), Some(&4));
+    assert_eq!(flat.rrr(), Some(&8));
+    assert_eq!(AnyOf8::from_opt8(flat.opt8()), flat.as_nested());
+    assert!(FlatAnyOf8::<u8>::from_opt8(Default::default()).is_empty());
+}
+
+#[test]
+fn test_flat_leaves_mut() {
+    let mut flat: FlatAnyOf8<i32> =
+        FlatAnyOf8::from_opt8((Some(1), None, None, None, None, None, None, Some(8)));
+    *flat.lll_mut().unwrap() += 10;
+    assert_eq!(flat.llr_mut(), None);
+    assert_eq!(flat.lll(), Some(&11));
+    assert_eq!(flat.shape(), 0b1000_0001);
+
+    let mut flat: FlatAnyOf16<i32> = FlatAnyOf16::from_nested(AnyOf16::new_right(
+        AnyOf8::new_right(AnyOf4::new_right(AnyOf::new_right(16))),
+    ));
+    if let Some(rrrr) = flat.rrrr_mut() {
+        *rrrr = -16;
+    }
+    assert_eq!(flat.llll_mut(), None);
+    assert_eq!(flat.into_opt16().15, Some(-16));
+}
+
+#[test]
+fn test_flat8_round_trip() {
+    let shapes: [Opt8<u8, u8, u8, u8, u8, u8, u8, u8>; 4] = [
+        (None, None, None, None, None, None, None, None),
+        (Some(1), None, None, None, None, None, None, None),
+        (None, None, Some(3), None, Some(5), None, None, None),
+        (
+            Some(1),
+            Some(2),
+            Some(3),
+            Some(4),
+            Some(5),
+            Some(6),
+            Some(7),
+            Some(8),
+        ),
+    ];
+    for opt in shapes {
+        let nested = AnyOf8::from_opt8(opt);
+        let flat = FlatAnyOf8::from(nested);
+        assert_eq!(flat.presence_count(), nested.presence_count8());
+        assert_eq!(flat.as_nested(), AnyOf8::from_opt8(nested.opt8()));
+        assert_eq!(flat.clone().into_opt8(), opt);
+        assert_eq!(AnyOf8::from(flat.clone()), nested);
+        assert_eq!(flat, FlatAnyOf8::from(opt));
+    }
+}
+
+#[test]
+fn test_flat16() {
+    let nested: AnyOf16<i32> = AnyOf16::new_both(
+        AnyOf8::new_left(AnyOf4::new_left(AnyOf::new_left(1))),
+        AnyOf8::new_right(AnyOf4::new_right(AnyOf::new_right(16))),
+    );
+    let flat = FlatAnyOf16::from_nested(nested);
+    assert_eq!(flat.shape(), 0b1000_0000_0000_0001);
+    assert_eq!(flat.llll(), Some(&1));
+    assert_eq!(flat.rrrr(), Some(&16));
+    assert_eq!(flat.rrrl(), None);
+    assert_eq!(AnyOf16::from_opt16(flat.opt16()), AnyOf16::from_opt16(nested.opt16()));
+    assert_eq!(flat.into_nested(), nested);
+}
+
+#[test]
+fn test_flat_size() {
+    assert!(size_of::<FlatAnyOf8<u8>>() < size_of::<AnyOf8<u8>>());
+    assert!(size_of::<FlatAnyOf16<u8>>() < size_of::<AnyOf16<u8>>());
+    assert_eq!(size_of::<FlatAnyOf8<u8>>(), 9);
+    assert_eq!(size_of::<FlatAnyOf16<u8>>(), 18);
+}
+
+#[test]
+fn test_flat_order() {
+    let left: FlatAnyOf8<i32> =
+        FlatAnyOf8::from_opt8((Some(1), None, None, None, None, None, None, None));
+    let right: FlatAnyOf8<i32> =
+        FlatAnyOf8::from_opt8((None, None, None, None, Some(1), None, None, None));
+    assert!(left < right);
+    assert_eq!(left.cmp(&right), left.as_nested().cmp(&right.as_nested()));
+}
+
+#[test]
+fn test_flat_drop() {
+    let value = Rc::new(0);
+    {
+        let flat: FlatAnyOf8<Rc<i32>> = FlatAnyOf8::from_opt8((
+            Some(value.clone()),
+            None,
+            Some(value.clone()),
+            None,
+            None,
+            None,
+            None,
+            Some(value.clone()),
+        ));
+        assert_eq!(Rc::strong_count(&value), 4);
+        let copy = flat.clone();
+        assert_eq!(Rc::strong_count(&value), 7);
+        drop(copy);
+        assert_eq!(Rc::strong_count(&value), 4);
+        let nested = flat.into_nested();
+        assert_eq!(Rc::strong_count(&value), 4);
+        let flat = FlatAnyOf8::from_nested(nested);
+        let (first, ..) = flat.into_opt8();
+        assert_eq!(Rc::strong_count(&value), 2);
+        drop(first);
+    }
+    assert_eq!(Rc::strong_count(&value), 1);
+}
@@ -0,0 +1,114 @@
+use crate::*;
+use core::future::{ready, Future, IntoFuture};
+use core::pin::Pin;
+use core::task::{Context, Poll};
+use futures::executor::block_on;
+use futures::stream::{self, Stream, StreamExt};
+use std::vec::Vec;
+
+/// Returns `Pending` a given number of times before resolving to its value.
+struct Delayed<T> {
+    polls: usize,
+    value: Option<T>,
+}
+
+fn delayed<T>(polls: usize, value: T) -> Delayed<T> {
+    Delayed {
+        polls,
+        value: Some(value),
+    }
+}
+
+impl<T: Unpin> Future for Delayed<T> {
+    type Output = T;
+
+    fn poll(mut self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<T> {
+        if self.polls == 0 {
+            Poll::Ready(self.value.take().expect("polled after completion"))
+        } else {
+            self.polls -= 1;
+            cx.waker().wake_by_ref();
+            Poll::Pending
+        }
+    }
+}
+
+#[test]
+fn test_either_future() {
+    let left: EitherOf<_, Delayed<i32>> = Left(async { 1 });
+    assert_eq!(block_on(left), 1);
+    let right: EitherOf<core::future::Ready<i32>, _> = Right(delayed(2, 2));
+    assert_eq!(block_on(right), 2);
+}
+
+#[test]
+fn test_either_stream() {
+    let left: EitherOf<_, stream::Empty<i32>> = Left(stream::iter([1, 2, 3]));
+    assert_eq!(left.size_hint(), (3, Some(3)));
+    assert_eq!(block_on(left.collect::<Vec<_>>()), [1, 2, 3]);
+    let right: EitherOf<stream::Iter<core::array::IntoIter<i32, 1>>, _> = Right(stream::empty());
+    assert!(block_on(right.collect::<Vec<_>>()).is_empty());
+}
+
+#[test]
+fn test_both_join() {
+    let both = BothOf::new(delayed(3, 1), delayed(1, "one"));
+    assert_eq!(block_on(both.into_future()), BothOf::new(1, "one"));
+    let both = BothOf::new(async { 1 }, ready("one"));
+    assert_eq!(block_on(async { both.await }), BothOf::new(1, "one"));
+}
+
+#[test]
+fn test_any_of_future() {
+    let neither: AnyOf<Delayed<i32>, Delayed<&str>> = AnyOf::new_neither();
+    assert_eq!(block_on(neither.join()), AnyOf::new_neither());
+    let left: AnyOf<_, Delayed<&str>> = AnyOf::new_left(delayed(2, 1));
+    assert_eq!(block_on(left.join()), AnyOf::new_left(1));
+    let right: AnyOf<Delayed<i32>, _> = AnyOf::new_right(delayed(2, "one"));
+    assert_eq!(block_on(right.join()), AnyOf::new_right("one"));
+    let both = AnyOf::new_both(delayed(1, 1), delayed(4, "one"));
+    assert_eq!(block_on(async { both.await }), AnyOf::new_both(1, "one"));
+}
+
+#[test]
+fn test_pin_projection() {
+    let mut either: EitherOf<i32, &str> = Left(1);
+    let pinned = Pin::new(&mut either);
+    if let Left(mut l) = pinned.as_pin_mut() {
+        *l = 2;
+    }
+    assert_eq!(either, Left(2));
+
+    let mut any = AnyOf::new_both(1, 2);
+    let projected = Pin::new(&mut any).as_pin_mut();
+    if let Both(BothOf { mut left, right }) = projected {
+        *left += *right;
+    }
+    assert_eq!(any, AnyOf::new_both(3, 2));
+    assert_eq!(Pin::new(&any).as_pin_ref().right().map(|r| **r), Some(2));
+}
+
+#[test]
+fn test_race() {
+    use crate::future::race;
+
+    assert_eq!(block_on(race(delayed(1, 1), delayed(3, "one"))), AnyOf::new_left(1));
+    assert_eq!(block_on(race(delayed(3, 1), delayed(1, "one"))), AnyOf::new_right("one"));
+    assert_eq!(block_on(race(delayed(2, 1), delayed(2, "one"))), AnyOf::new_both(1, "one"));
+    assert_eq!(
+        block_on(BothOf::new(delayed(0, 1), delayed(0, "one")).race()),
+        AnyOf::new_both(1, "one")
+    );
+}
+
+#[test]
+fn test_race_extra_polls() {
+    use crate::future::race;
+
+    let late = race(delayed(1, 1), delayed(3, "one")).extra_polls(2);
+    assert_eq!(block_on(late), AnyOf::new_both(1, "one"));
+    let too_late = race(delayed(1, 1), delayed(4, "one")).extra_polls(2);
+    assert_eq!(block_on(too_late), AnyOf::new_left(1));
+    let never = race(core::future::pending::<i32>(), delayed(0, "one")).extra_polls(5);
+    assert_eq!(block_on(never), AnyOf::new_right("one"));
+}
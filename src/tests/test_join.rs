@@ -0,0 +1,46 @@
+use crate::join::{outer_join, outer_join_by_key};
+use crate::*;
+use std::vec;
+use std::vec::Vec;
+
+#[test]
+fn test_outer_join() {
+    let joined: Vec<_> = outer_join(&[1, 3, 5], &[1, 2, 5, 6]).collect();
+    assert_eq!(
+        joined,
+        vec![
+            AnyOf::new_both(&1, &1),
+            AnyOf::new_right(&2),
+            AnyOf::new_left(&3),
+            AnyOf::new_both(&5, &5),
+            AnyOf::new_right(&6),
+        ]
+    );
+    assert_eq!(outer_join::<i32>(&[], &[]).next(), None);
+    assert_eq!(outer_join(&[1, 2], &[]).count(), 2);
+}
+
+#[test]
+fn test_outer_join_duplicates() {
+    let joined: Vec<_> = outer_join(&[1, 1, 1], &[1, 1]).collect();
+    assert_eq!(
+        joined,
+        vec![
+            AnyOf::new_both(&1, &1),
+            AnyOf::new_both(&1, &1),
+            AnyOf::new_left(&1)
+        ]
+    );
+}
+
+#[test]
+fn test_outer_join_by_key() {
+    let left = [(1, 'a'), (2, 'b')];
+    let right = ["2", "3"];
+    let mut joined = outer_join_by_key(&left, &right, |l| l.0, |r| r.parse::<i32>().unwrap());
+    assert_eq!(joined.size_hint(), (2, Some(4)));
+    assert_eq!(joined.next(), Some(AnyOf::new_left(&(1, 'a'))));
+    assert_eq!(joined.next(), Some(AnyOf::new_both(&(2, 'b'), &"2")));
+    assert_eq!(joined.next(), Some(AnyOf::new_right(&"3")));
+    assert_eq!(joined.next(), None);
+}
@@ -0,0 +1,51 @@
+use crate::ffi::FfiEitherOf;
+use crate::*;
+use core::mem::{align_of, offset_of, size_of};
+
+#[test]
+fn test_both_of_repr_c() {
+    assert_eq!(offset_of!(BothOf<u8, u32>, left), 0);
+    assert_eq!(offset_of!(BothOf<u8, u32>, right), 4);
+    assert_eq!(size_of::<BothOf<u8, u32>>(), 8);
+
+    // The fields are not reordered.
+    assert_eq!(offset_of!(BothOf<u32, u8>, left), 0);
+    assert_eq!(offset_of!(BothOf<u32, u8>, right), 4);
+    assert_eq!(size_of::<BothOf<u8, u64>>(), 16);
+    assert_eq!(offset_of!(BothOf<u16, u8>, right), 2);
+    assert_eq!(size_of::<BothOf<[u8; 3], u8>>(), 4);
+}
+
+#[test]
+fn test_ffi_either_of_repr_c_u8() {
+    assert_eq!(size_of::<FfiEitherOf<u8, u32>>(), 8);
+    assert_eq!(align_of::<FfiEitherOf<u8, u32>>(), 4);
+    assert_eq!(size_of::<FfiEitherOf<u64>>(), 16);
+    assert_eq!(size_of::<FfiEitherOf<u8>>(), 2);
+
+    let left: FfiEitherOf<u8, u32> = FfiEitherOf::Left(7);
+    let right: FfiEitherOf<u8, u32> = FfiEitherOf::Right(0x0102_0304);
+    // SAFETY: `FfiEitherOf` is `repr(C, u8)`: the tag is the first byte,
+    // and the union of the payloads is at the alignment of `u32`.
+    unsafe {
+        let left_ptr = &left as *const FfiEitherOf<u8, u32> as *const u8;
+        assert_eq!(*left_ptr, 0);
+        assert_eq!(*left_ptr.add(4), 7);
+        let right_ptr = &right as *const FfiEitherOf<u8, u32> as *const u8;
+        assert_eq!(*right_ptr, 1);
+        assert_eq!(*(right_ptr.add(4) as *const u32), 0x0102_0304);
+    }
+}
+
+#[test]
+fn test_ffi_either_of_conversions() {
+    let left: EitherOf<i32, char> = Left(1);
+    let right: EitherOf<i32, char> = Right('a');
+    assert_eq!(FfiEitherOf::from(left), FfiEitherOf::Left(1));
+    assert_eq!(FfiEitherOf::from(right).into_either_of(), right);
+    assert_eq!(
+        FfiEitherOf::<i32, char>::Right('b').as_either_of(),
+        Right(&'b')
+    );
+    assert_eq!(EitherOf::from(FfiEitherOf::<i32, char>::Left(2)), Left(2));
+}
@@ -0,0 +1,93 @@
+use crate::*;
+use std::format;
+use std::string::{String, ToString};
+
+fn describe(value: AnyOf<i32, char>) -> String {
+    match_any!(value, {
+        () => "neither".to_string(),
+        (l, _) if l < 0 => format!("negative {l}"),
+        (l, _) => format!("left {l}"),
+        (_, r) => format!("right {r}"),
+        (l, r) => format!("both {l} {r}"),
+    })
+}
+
+#[test]
+fn test_match_any_shapes() {
+    assert_eq!(describe(AnyOf::new_neither()), "neither");
+    assert_eq!(describe(AnyOf::new_left(-1)), "negative -1");
+    assert_eq!(describe(AnyOf::new_left(1)), "left 1");
+    assert_eq!(describe(AnyOf::new_right('a')), "right a");
+    assert_eq!(describe(AnyOf::new_both(1, 'a')), "both 1 a");
+}
+
+#[test]
+fn test_match_any_patterns_and_references() {
+    let any: AnyOf<(i32, i32), Option<char>> = AnyOf::new_both((1, 2), Some('a'));
+    let matched = match_any!(&any, {
+        ((a, b), Some(c)) => (a + b, *c),
+        _ => (-1, ' '),
+    });
+    assert_eq!(matched, (3, 'a'));
+
+    let slots = match_any!(AnyOf::<i32, i32>::new_right(5), {
+        {} => 0,
+        { l: a } => a,
+        { r: b } => b * 10,
+        { l: a, r: b } => a + b,
+    });
+    assert_eq!(slots, 50);
+}
+
+#[test]
+fn test_match_any_slots4() {
+    let count = |any4: AnyOf4<i32>| {
+        match_any!(any4, {
+            {} => 0,
+            { ll: a, rr: d } => a + d,
+            { rr: 0 } => -1,
+            { lr: b, rl: c, } => b * c,
+            _ => 100,
+        })
+    };
+    assert_eq!(count(AnyOf4::new4(None, None, None, None)), 0);
+    assert_eq!(count(AnyOf4::new4(Some(1), None, None, Some(4))), 5);
+    assert_eq!(count(AnyOf4::new4(None, None, None, Some(0))), -1);
+    assert_eq!(count(AnyOf4::new4(None, Some(2), Some(3), None)), 6);
+    assert_eq!(count(AnyOf4::new4(Some(1), Some(2), None, None)), 100);
+}
+
+#[test]
+fn test_match_any_slots8_and_16() {
+    let any8: AnyOf8<i32> = AnyOf8::new8(None, Some(2), None, None, None, None, Some(7), None);
+    let matched = match_any!(any8, {
+        { llr: b, rrl: g } => b * g,
+        _ => 0,
+    });
+    assert_eq!(matched, 14);
+
+    let any16: AnyOf16<i32> = AnyOf16::new16(
+        Some(1),
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        Some(16),
+    );
+    let matched = match_any!(any16, {
+        { rrrr: p, llll: a } => a + p,
+        { llll: a } => a,
+        _ => 0,
+    });
+    assert_eq!(matched, 17);
+}
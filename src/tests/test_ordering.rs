@@ -0,0 +1,110 @@
+use crate::*;
+use core::cmp::Ordering;
+
+#[test]
+fn test_default_order() {
+    let neither: AnyOf<i32, i32> = AnyOf::new_neither();
+    let left: AnyOf<i32, i32> = AnyOf::new_left(9);
+    let right: AnyOf<i32, i32> = AnyOf::new_right(0);
+    let both: AnyOf<i32, i32> = AnyOf::new_both(0, 0);
+    assert!(neither < left);
+    assert!(left < right);
+    assert!(right < both);
+    assert!(AnyOf::<i32, i32>::new_left(1) < AnyOf::new_left(2));
+    assert!(AnyOf::new_both(1, 9) < AnyOf::new_both(2, 0));
+
+    let l: EitherOf<i32, i32> = Left(9);
+    let r: EitherOf<i32, i32> = Right(0);
+    assert!(l < r);
+    assert!(BothOf::new(1, 9) < BothOf::new(2, 0));
+    assert!(BothOf::new(1, 1) < BothOf::new(1, 2));
+}
+
+#[test]
+fn test_btree_set() {
+    use std::collections::BTreeSet;
+
+    let set: BTreeSet<AnyOf<i32, &str>> = [
+        AnyOf::new_both(1, "b"),
+        AnyOf::new_right("a"),
+        AnyOf::new_left(2),
+        AnyOf::new_neither(),
+        AnyOf::new_left(1),
+    ]
+    .into_iter()
+    .collect();
+    let sorted: std::vec::Vec<_> = set.into_iter().collect();
+    assert_eq!(
+        sorted,
+        [
+            AnyOf::new_neither(),
+            AnyOf::new_left(1),
+            AnyOf::new_left(2),
+            AnyOf::new_right("a"),
+            AnyOf::new_both(1, "b"),
+        ]
+    );
+}
+
+#[test]
+fn test_cmp_by_left_first() {
+    let mut values: [AnyOf<i32, i32>; 5] = [
+        AnyOf::new_both(1, 1),
+        AnyOf::new_left(1),
+        AnyOf::new_right(5),
+        AnyOf::new_neither(),
+        AnyOf::new_both(0, 9),
+    ];
+    values.sort_by(AnyOf::cmp_by_left_first);
+    assert_eq!(
+        values,
+        [
+            AnyOf::new_neither(),
+            AnyOf::new_right(5),
+            AnyOf::new_both(0, 9),
+            AnyOf::new_left(1),
+            AnyOf::new_both(1, 1),
+        ]
+    );
+    assert_eq!(
+        BothOf::new(1, 2).cmp_by_right_first(&BothOf::new(2, 1)),
+        Ordering::Greater
+    );
+    assert_eq!(
+        AnyOf::new_left(3).cmp_by_left_first(&AnyOf::new_both(1, 1)),
+        Ordering::Greater
+    );
+}
+
+#[test]
+fn test_cmp_by_presence() {
+    let both: AnyOf<i32, i32> = AnyOf::new_both(0, 0);
+    let right: AnyOf<i32, i32> = AnyOf::new_right(9);
+    assert_eq!(both.presence_count(), 2);
+    assert_eq!(right.cmp_by_presence(&both), Ordering::Less);
+
+    let two: AnyOf4<i32> = AnyOf4::new4(Some(1), None, None, Some(1));
+    let one: AnyOf4<i32> = AnyOf4::new4(None, None, None, Some(9));
+    let three: AnyOf4<i32> = AnyOf4::new4(Some(0), Some(0), Some(0), None);
+    assert_eq!(two.presence_count4(), 2);
+    let mut values = [three, two, one];
+    values.sort_by(AnyOf4::cmp_by_presence4);
+    assert_eq!(values, [one, two, three]);
+    assert!(one < two);
+
+    let eight: AnyOf8<i32> = AnyOf8::new8(Some(1), None, None, None, None, None, None, Some(2));
+    let seven: AnyOf8<i32> = AnyOf8::new8(Some(1), Some(1), None, None, None, None, None, None);
+    assert_eq!(eight.presence_count8(), 2);
+    assert_eq!(eight.cmp_by_presence8(&seven), Ordering::Greater);
+    assert_eq!(eight.cmp(&seven), Ordering::Greater);
+
+    let sixteen: AnyOf16<i32> = AnyOf16::new_right(AnyOf8::new_left(AnyOf4::new_both(
+        AnyOf::new_left(1),
+        AnyOf::new_right(2),
+    )));
+    assert_eq!(sixteen.presence_count16(), 2);
+    assert_eq!(
+        sixteen.cmp_by_presence16(&AnyOf16::new_neither()),
+        Ordering::Greater
+    );
+}
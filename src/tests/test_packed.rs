@@ -0,0 +1,78 @@
+use crate::*;
+use core::mem::size_of;
+use std::rc::Rc;
+
+#[test]
+fn test_packed_size() {
+    assert_eq!(size_of::<PackedAnyOf<u64, u8>>(), 16);
+    assert_eq!(size_of::<AnyOf<u64, u8>>(), 24);
+    assert_eq!(size_of::<PackedAnyOf<u16, u32>>(), 8);
+    assert_eq!(size_of::<AnyOf<u16, u32>>(), 12);
+    assert_eq!(size_of::<PackedAnyOf<u32, u8>>(), 8);
+    assert_eq!(size_of::<AnyOf<u32, u8>>(), 12);
+    // No saving when both sides have the same alignment.
+    assert_eq!(size_of::<PackedAnyOf<u8, u8>>(), 3);
+    assert_eq!(size_of::<AnyOf<u8, u8>>(), 3);
+    assert_eq!(size_of::<PackedAnyOf<u32, u32>>(), 12);
+    assert_eq!(size_of::<AnyOf<u32, u32>>(), 12);
+    assert_eq!(size_of::<PackedAnyOf<[u16; 3], u16>>(), 10);
+    assert_eq!(size_of::<AnyOf<[u16; 3], u16>>(), 10);
+}
+
+#[test]
+fn test_packed_round_trip() {
+    let values: [AnyOf<i32, &str>; 4] = [
+        AnyOf::new_neither(),
+        AnyOf::new_left(1),
+        AnyOf::new_right("one"),
+        AnyOf::new_both(1, "one"),
+    ];
+    for value in values {
+        let packed = PackedAnyOf::from(value);
+        assert_eq!(packed.has_left(), value.has_left());
+        assert_eq!(packed.has_right(), value.has_right());
+        assert_eq!(packed.is_either(), value.is_either());
+        assert_eq!(packed.as_any_of(), AnyOf::new(value.left(), value.right()));
+        assert_eq!(AnyOf::from(packed.clone()), value);
+        assert_eq!(packed, PackedAnyOf::from_any_of(value));
+    }
+}
+
+#[test]
+fn test_packed_api() {
+    let packed: PackedAnyOf<i32, &str> = PackedAnyOf::new_both(2, "text");
+    assert_eq!(packed.tag(), 0b11);
+    assert_eq!(packed.opt2(), (Some(&2), Some(&"text")));
+
+    let swapped = packed.clone().swap();
+    assert_eq!(swapped.left(), Some(&"text"));
+    assert_eq!((!swapped).right(), Some(&"text"));
+
+    let mapped = packed.clone().map(|l| l * 3, |r| r.len());
+    assert_eq!(mapped.into_any_of(), AnyOf::new_both(6, 4));
+
+    let left: PackedAnyOf<i32, &str> = PackedAnyOf::new_left(1);
+    assert_eq!(left.clone().right_or("default"), "default");
+    assert_eq!(left.clone().unwrap_left(), 1);
+    assert!(PackedAnyOf::<i32, i32>::new_neither().is_neither());
+    assert!(left < packed);
+}
+
+#[test]
+fn test_packed_drop() {
+    let left = Rc::new(1);
+    let right = Rc::new(2);
+    {
+        let packed = PackedAnyOf::new_both(left.clone(), right.clone());
+        assert_eq!(Rc::strong_count(&left), 2);
+        let copy = packed.clone();
+        assert_eq!(Rc::strong_count(&right), 3);
+        drop(copy);
+        let filtered = PackedAnyOf::<Rc<i32>, _>::new(None, packed.into_opt2().1);
+        assert_eq!(Rc::strong_count(&left), 1);
+        assert_eq!(Rc::strong_count(&right), 2);
+        drop(filtered);
+    }
+    assert_eq!(Rc::strong_count(&left), 1);
+    assert_eq!(Rc::strong_count(&right), 1);
+}
@@ -0,0 +1,74 @@
+use crate::join::outer_join;
+use crate::parallel::{ParallelPartition, par_outer_join, par_outer_join_by_key};
+use crate::*;
+use rayon::prelude::*;
+use std::string::ToString;
+use std::vec;
+use std::vec::Vec;
+
+#[test]
+fn test_parallel_either() {
+    let either: EitherOf<rayon::range::Iter<i32>, rayon::vec::IntoIter<i32>> =
+        Left((0..100).into_par_iter());
+    assert_eq!(either.len(), 100);
+    assert_eq!(either.sum::<i32>(), 4950);
+    let either: EitherOf<rayon::range::Iter<i32>, rayon::vec::IntoIter<i32>> =
+        Right(vec![1, 2, 3].into_par_iter());
+    assert_eq!(
+        ParallelIterator::map(either, |n| n * 2).collect::<Vec<_>>(),
+        vec![2, 4, 6]
+    );
+}
+
+#[test]
+fn test_parallel_partition() {
+    let both = (0..1000i32).into_par_iter().partition_both(|n| {
+        if n % 3 == 0 {
+            Left(n)
+        } else {
+            Right(n.to_string())
+        }
+    });
+    assert_eq!(both.left.len(), 334);
+    assert_eq!(both.right.len(), 666);
+    assert!(both.left.windows(2).all(|w| w[0] < w[1]));
+
+    let values = vec![
+        AnyOf::new_left(1),
+        AnyOf::new_both(2, 'b'),
+        AnyOf::new_neither(),
+        AnyOf::new_right('c'),
+    ];
+    let both = values.into_par_iter().unzip_any();
+    assert_eq!(both, BothOf::new(vec![1, 2], vec!['b', 'c']));
+}
+
+#[test]
+fn test_parallel_collect() {
+    let any: AnyOf<Vec<i32>, Vec<char>> = vec![AnyOf::new_left(1), AnyOf::new_both(2, 'b')]
+        .into_par_iter()
+        .collect();
+    assert_eq!(any, AnyOf::new_both(vec![1, 2], vec!['b']));
+    let any: AnyOf<Vec<i32>, Vec<char>> = vec![Right('a'), Right('b')].into_par_iter().collect();
+    assert_eq!(any, AnyOf::new_right(vec!['a', 'b']));
+    let any: AnyOf<Vec<i32>, Vec<char>> = Vec::<AnyOf<i32, char>>::new().into_par_iter().collect();
+    assert_eq!(any, AnyOf::new_neither());
+}
+
+#[test]
+fn test_parallel_outer_join() {
+    let left: Vec<u32> = (0..50_000).map(|n| n / 3 * 2).collect();
+    let right: Vec<u32> = (0..40_000).map(|n| n / 2 * 3).collect();
+    let sequential: Vec<_> = outer_join(&left, &right).collect();
+    assert_eq!(par_outer_join(&left, &right), sequential);
+
+    let same = vec![7u32; 10_000];
+    assert_eq!(
+        par_outer_join(&same, &left),
+        outer_join(&same, &left).collect::<Vec<_>>()
+    );
+
+    let keyed: Vec<(u32, char)> = right.iter().map(|&n| (n, 'r')).collect();
+    let joined = par_outer_join_by_key(&left, &keyed, |l| *l, |r| r.0);
+    assert_eq!(joined.len(), sequential.len());
+}
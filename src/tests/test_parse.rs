@@ -0,0 +1,148 @@
+use crate::parse::ParseError;
+use crate::*;
+use std::string::{String, ToString};
+
+type Result<T> =
+    core::result::Result<T, ParseError<core::num::ParseIntError, core::str::ParseBoolError>>;
+
+#[test]
+fn test_parse_round_trip() {
+    let values: [AnyOf<i32, bool>; 4] = [
+        AnyOf::new_neither(),
+        AnyOf::new_left(-1),
+        AnyOf::new_right(true),
+        AnyOf::new_both(42, false),
+    ];
+    for value in values {
+        assert_eq!(value.to_string().parse(), Ok(value));
+    }
+    let either: EitherOf<i32, bool> = Right(false);
+    assert_eq!(either.to_string().parse(), Ok(either));
+    let both: BothOf<i32, bool> = BothOf::new(1, true);
+    assert_eq!(both.to_string().parse(), Ok(both));
+}
+
+#[test]
+fn test_parse_nested() {
+    for bits in 0..16u8 {
+        let leaf = |i: u8| (bits & (1 << i) != 0).then_some(i as i32);
+        let any4: AnyOf4<i32> = AnyOf4::new4(leaf(0), leaf(1), leaf(2), leaf(3));
+        assert_eq!(any4.to_string().parse(), Ok(any4));
+    }
+    let any8: AnyOf8<i32> =
+        AnyOf8::new_both(AnyOf4::new_left(AnyOf::new_right(1)), AnyOf4::new_neither());
+    assert_eq!(any8.to_string(), "B(L(R(1)), _)");
+    assert_eq!(any8.to_string().parse(), Ok(any8));
+}
+
+#[test]
+fn test_parse_strings() {
+    let any: AnyOf<String, String> = "B(a(b, c), d)".parse().unwrap();
+    assert_eq!(any, AnyOf::new_both("a(b, c)".to_string(), "d".to_string()));
+    let any: AnyOf<String, String> = "L()".parse().unwrap();
+    assert_eq!(any, AnyOf::new_left(String::new()));
+}
+
+#[test]
+fn test_display_escapes() {
+    let any: AnyOf<&str, &str> = AnyOf::new_both("a, b", "c");
+    assert_eq!(any.to_string(), r"B(a\, b, c)");
+    assert_eq!(AnyOf::<&str, &str>::new_left("(x").to_string(), r"L(\(x)");
+    assert_eq!(AnyOf::<&str, &str>::new_right("y)").to_string(), r"R(y\))");
+    assert_eq!(
+        AnyOf::<&str, &str>::new_left(r"a\b").to_string(),
+        r"L(a\\b)"
+    );
+    assert_eq!(
+        AnyOf::<&str, &str>::new_left("f(a, b)").to_string(),
+        "L(f(a, b))"
+    );
+    assert_eq!(AnyOf::<&str, &str>::new_left("a,b").to_string(), "L(a,b)");
+    let any4: AnyOf4<&str> = AnyOf4::new4(Some("a, b"), None, None, Some("c"));
+    assert_eq!(any4.to_string(), r"B(L\(a\\\, b\), R(c))");
+}
+
+#[cfg(feature = "alloc")]
+#[test]
+fn test_parse_escaped_round_trip() {
+    let strings = ["a, b", "c", "(x", "y)", r"a\b", ")(", ", ", ""].map(String::from);
+    for l in &strings {
+        for r in &strings {
+            let values: [AnyOf<String, String>; 3] = [
+                AnyOf::new_left(l.clone()),
+                AnyOf::new_right(r.clone()),
+                AnyOf::new_both(l.clone(), r.clone()),
+            ];
+            for value in values {
+                assert_eq!(value.to_string().parse(), Ok(value));
+            }
+            let any4: AnyOf4<String> =
+                AnyOf4::new4(Some(l.clone()), None, Some(r.clone()), Some(l.clone()));
+            assert_eq!(any4.to_string().parse(), Ok(any4));
+        }
+    }
+}
+
+#[cfg(not(feature = "alloc"))]
+#[test]
+fn test_parse_escaped_without_alloc() {
+    assert_eq!(
+        r"B(1, a\, b)".parse::<AnyOf<i32, String>>(),
+        Err(ParseError::Syntax {
+            position: 6,
+            expected: "a value without `\\` (unescaping requires the `alloc` feature)",
+        })
+    );
+}
+
+#[test]
+fn test_parse_syntax_errors() {
+    let syntax = |position, expected| ParseError::Syntax { position, expected };
+    let parse = |s: &str| -> Result<AnyOf<i32, bool>> { s.parse() };
+    assert_eq!(parse(""), Err(syntax(0, "`_`, `L(`, `R(` or `B(`")));
+    assert_eq!(parse("X(1)"), Err(syntax(0, "`_`, `L(`, `R(` or `B(`")));
+    assert_eq!(parse("__"), Err(syntax(1, "end of input")));
+    assert_eq!(parse("L1"), Err(syntax(1, "`(`")));
+    assert_eq!(parse("L(1"), Err(syntax(3, "`)`")));
+    assert_eq!(parse("L(1))"), Err(syntax(4, "end of input")));
+    assert_eq!(parse("B(1,true)"), Err(syntax(8, "`, `")));
+
+    let either: Result<EitherOf<i32, bool>> = "_".parse();
+    assert_eq!(either, Err(syntax(0, "`L(` or `R(`")));
+    let both: Result<BothOf<i32, bool>> = "L(1)".parse();
+    assert_eq!(both, Err(syntax(0, "`B(`")));
+}
+
+#[test]
+fn test_parse_value_errors() {
+    let any: Result<AnyOf<i32, bool>> = "B(x, true)".parse();
+    let Err(ParseError::Left { position, error }) = any else {
+        panic!("the left value is invalid");
+    };
+    assert_eq!(position, 2);
+    assert_eq!(error, "x".parse::<i32>().unwrap_err());
+
+    let any: Result<AnyOf<i32, bool>> = "B(12, yes)".parse();
+    let Err(ParseError::Right { position, .. }) = any else {
+        panic!("the right value is invalid");
+    };
+    assert_eq!(position, 6);
+    assert_eq!(
+        any.unwrap_err().to_string(),
+        "invalid right value at position 6: provided string was not `true` or `false`"
+    );
+
+    let any4 = "B(R(1), L(x))".parse::<AnyOf4<i32>>();
+    let Err(ParseError::Right { position, error }) = any4 else {
+        panic!("the right value is invalid");
+    };
+    assert_eq!(position, 8);
+    let ParseError::Left { position, .. } = error else {
+        panic!("the left leaf of the right value is invalid");
+    };
+    assert_eq!(position, 2);
+    assert_eq!(
+        "B(1)".parse::<AnyOf<i32, bool>>().unwrap_err().to_string(),
+        "expected `, ` at position 3"
+    );
+}
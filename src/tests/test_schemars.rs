@@ -0,0 +1,55 @@
+use crate::tristate::Tristate;
+use crate::*;
+use schemars::{JsonSchema, schema_for};
+use serde_json::json;
+
+#[derive(JsonSchema, serde::Serialize, serde::Deserialize)]
+struct Patch {
+    #[serde(default, skip_serializing_if = "Tristate::is_missing")]
+    name: Tristate<u8>,
+}
+
+#[test]
+fn test_any_of_schema() {
+    let schema = schema_for!(AnyOf<i32, bool>).to_value();
+    assert_eq!(schema["title"], "AnyOf_int32_and_boolean");
+    assert_eq!(
+        schema["oneOf"][0],
+        json!({"type": "string", "enum": ["Neither"]})
+    );
+    assert_eq!(
+        schema["oneOf"][1]["properties"]["Either"]["$ref"],
+        "#/$defs/EitherOf_int32_and_boolean"
+    );
+    assert_eq!(
+        schema["oneOf"][2]["properties"]["Both"]["$ref"],
+        "#/$defs/BothOf_int32_and_boolean"
+    );
+
+    let both = &schema["$defs"]["BothOf_int32_and_boolean"];
+    assert_eq!(both["required"], json!(["left", "right"]));
+    assert_eq!(both["properties"]["right"], json!({"type": "boolean"}));
+    let either = &schema["$defs"]["EitherOf_int32_and_boolean"];
+    assert_eq!(either["oneOf"][1]["required"], json!(["Right"]));
+}
+
+#[test]
+fn test_any_of_x_schema() {
+    let schema = schema_for!(AnyOf4<u8>).to_value();
+    assert_eq!(
+        schema["title"],
+        "AnyOf_AnyOf_uint8_and_uint8_and_AnyOf_uint8_and_uint8"
+    );
+    assert!(schema["$defs"]["AnyOf_uint8_and_uint8"].is_object());
+    assert!(schema["$defs"]["BothOf_AnyOf_uint8_and_uint8_and_AnyOf_uint8_and_uint8"].is_object());
+}
+
+#[test]
+fn test_tristate_schema() {
+    let schema = schema_for!(Patch).to_value();
+    assert_eq!(
+        schema["properties"]["name"]["type"],
+        json!(["integer", "null"])
+    );
+    assert!(schema.get("required").is_none());
+}
@@ -0,0 +1,36 @@
+use crate::*;
+use serde_json::json;
+
+#[test]
+fn test_serde_representation() {
+    let either: EitherOf<i32, bool> = Left(1);
+    assert_eq!(serde_json::to_value(either).unwrap(), json!({"Left": 1}));
+    assert_eq!(
+        serde_json::to_value(BothOf::new(1, true)).unwrap(),
+        json!({"left": 1, "right": true})
+    );
+
+    let values: [(AnyOf<i32, bool>, serde_json::Value); 4] = [
+        (AnyOf::new_neither(), json!("Neither")),
+        (AnyOf::new_left(1), json!({"Either": {"Left": 1}})),
+        (AnyOf::new_right(true), json!({"Either": {"Right": true}})),
+        (
+            AnyOf::new_both(1, false),
+            json!({"Both": {"left": 1, "right": false}}),
+        ),
+    ];
+    for (any, expected) in values {
+        assert_eq!(serde_json::to_value(any).unwrap(), expected);
+        assert_eq!(
+            serde_json::from_value::<AnyOf<i32, bool>>(expected).unwrap(),
+            any
+        );
+    }
+}
+
+#[test]
+fn test_serde_nested() {
+    let any4: AnyOf4<u8> = AnyOf4::new4(Some(1), None, None, Some(4));
+    let json = serde_json::to_string(&any4).unwrap();
+    assert_eq!(serde_json::from_str::<AnyOf4<u8>>(&json).unwrap(), any4);
+}
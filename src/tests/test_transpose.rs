@@ -0,0 +1,68 @@
+use crate::*;
+
+#[test]
+fn test_transpose_either() {
+    let left: EitherOf<Option<i32>, Option<char>> = Left(Some(1));
+    assert_eq!(left.transpose(), Some(Left(1)));
+    let right: EitherOf<Option<i32>, Option<char>> = Right(None);
+    assert_eq!(right.transpose(), None);
+
+    let left: EitherOf<Result<i32, &str>, Result<char, &str>> = Left(Err("left"));
+    assert_eq!(left.transpose(), Err("left"));
+    let right: EitherOf<Result<i32, &str>, Result<char, &str>> = Right(Ok('r'));
+    assert_eq!(right.transpose(), Ok(Right('r')));
+}
+
+#[test]
+fn test_transpose_both() {
+    assert_eq!(
+        BothOf::new(Some(1), Some('r')).transpose(),
+        Some(BothOf::new(1, 'r'))
+    );
+    assert_eq!(BothOf::new(Some(1), None::<char>).transpose(), None);
+    assert_eq!(BothOf::new(None::<i32>, Some('r')).transpose(), None);
+
+    let both: BothOf<Result<i32, &str>, Result<char, &str>> =
+        BothOf::new(Err("left"), Err("right"));
+    assert_eq!(both.transpose(), Err("left"));
+    let both: BothOf<Result<i32, &str>, Result<char, &str>> = BothOf::new(Ok(1), Err("right"));
+    assert_eq!(both.transpose(), Err("right"));
+    let both: BothOf<Result<i32, &str>, Result<char, &str>> = BothOf::new(Ok(1), Ok('r'));
+    assert_eq!(both.transpose(), Ok(BothOf::new(1, 'r')));
+}
+
+#[test]
+fn test_transpose_any_of() {
+    let any: AnyOf<Option<i32>, Option<char>> = AnyOf::new_both(Some(1), Some('r'));
+    assert_eq!(any.transpose(), AnyOf::new_both(1, 'r'));
+    let any: AnyOf<Option<i32>, Option<char>> = AnyOf::new_left(None);
+    assert_eq!(any.transpose(), AnyOf::new_neither());
+    let any: AnyOf<Option<i32>, Option<char>> = AnyOf::new_neither();
+    assert_eq!(any.transpose(), AnyOf::new_neither());
+
+    let any: AnyOf<Result<i32, &str>, Result<char, &str>> = AnyOf::new_neither();
+    assert_eq!(any.transpose(), Ok(AnyOf::new_neither()));
+    let any: AnyOf<Result<i32, &str>, Result<char, &str>> = AnyOf::new_left(Err("left"));
+    assert_eq!(any.transpose(), Err(AnyOf::new_left("left")));
+    let any: AnyOf<Result<i32, &str>, Result<char, &str>> = AnyOf::new_both(Err("left"), Ok('r'));
+    assert_eq!(any.transpose(), Err(AnyOf::new_left("left")));
+    let any: AnyOf<Result<i32, &str>, Result<char, &str>> = AnyOf::new_right(Ok('r'));
+    assert_eq!(any.transpose(), Ok(AnyOf::new_right('r')));
+}
+
+#[test]
+fn test_flatten() {
+    type Nested = AnyOf<AnyOf<i32, char>, AnyOf<i32, char>>;
+    let nested: Nested = AnyOf::new_neither();
+    assert_eq!(nested.flatten(), AnyOf::new_neither());
+    let nested: Nested = AnyOf::new_left(AnyOf::new_both(1, 'l'));
+    assert_eq!(nested.flatten(), AnyOf::new_both(1, 'l'));
+    let nested: Nested = AnyOf::new_both(AnyOf::new_right('l'), AnyOf::new_left(2));
+    assert_eq!(nested.flatten(), AnyOf::new_both(2, 'l'));
+    let nested: Nested = AnyOf::new_both(AnyOf::new_left(1), AnyOf::new_left(2));
+    assert_eq!(nested.flatten(), AnyOf::new_left(1));
+    let nested: Nested = AnyOf::new_both(AnyOf::new_right('l'), AnyOf::new_right('r'));
+    assert_eq!(nested.flatten(), AnyOf::new_right('r'));
+    let nested: Nested = AnyOf::new_both(AnyOf::new_both(1, 'l'), AnyOf::new_both(2, 'r'));
+    assert_eq!(nested.flatten(), AnyOf::new_both(1, 'l'));
+}
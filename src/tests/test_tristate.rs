@@ -0,0 +1,93 @@
+use crate::tristate::Tristate;
+use crate::*;
+use serde::{Deserialize, Serialize};
+use std::string::{String, ToString};
+
+#[derive(Serialize, Deserialize, Debug, PartialEq)]
+struct Patch {
+    #[serde(default, skip_serializing_if = "Tristate::is_missing")]
+    name: Tristate<String>,
+    #[serde(default, skip_serializing_if = "Tristate::is_missing")]
+    age: Tristate<u8>,
+}
+
+#[test]
+fn test_states() {
+    let missing: Tristate<u8> = Tristate::missing();
+    assert!(missing.is_missing() && !missing.is_null() && !missing.is_value());
+    assert_eq!(missing, Tristate::default());
+    let null: Tristate<u8> = Tristate::null();
+    assert!(null.is_null() && !null.is_missing() && !null.is_value());
+    let value = Tristate::value(1);
+    assert!(value.is_value() && !value.is_missing() && !value.is_null());
+    assert_eq!(value.as_value(), Some(&1));
+    assert_eq!(null.as_value(), None);
+}
+
+#[test]
+fn test_conversions() {
+    for option in [None, Some(None), Some(Some(1))] {
+        let tristate = Tristate::from(option);
+        assert_eq!(tristate.into_option(), option);
+        assert_eq!(Tristate::from(tristate.into_any_of()), tristate);
+    }
+    assert_eq!(Tristate::<u8>::null().into_any_of(), AnyOf::new_left(()));
+    assert_eq!(*Tristate::value(1).as_any_of(), AnyOf::new_right(1));
+    assert_eq!(Tristate::from(AnyOf::new_both((), 1)), Tristate::value(1));
+}
+
+#[test]
+fn test_patch_and_apply() {
+    let base = Tristate::value(1);
+    assert_eq!(base.patch(Tristate::missing()), base);
+    assert_eq!(base.patch(Tristate::null()), Tristate::null());
+    assert_eq!(base.patch(Tristate::value(2)), Tristate::value(2));
+
+    let mut target = Some(1);
+    Tristate::missing().apply_to(&mut target);
+    assert_eq!(target, Some(1));
+    Tristate::value(2).apply_to(&mut target);
+    assert_eq!(target, Some(2));
+    Tristate::null().apply_to(&mut target);
+    assert_eq!(target, None);
+}
+
+#[test]
+fn test_deserialize() {
+    let patch: Patch = serde_json::from_str("{}").unwrap();
+    assert!(patch.name.is_missing() && patch.age.is_missing());
+
+    let patch: Patch = serde_json::from_str(r#"{"name": null, "age": 42}"#).unwrap();
+    assert!(patch.name.is_null());
+    assert_eq!(patch.age, Tristate::value(42));
+
+    assert!(serde_json::from_str::<Patch>(r#"{"age": "x"}"#).is_err());
+}
+
+#[test]
+fn test_serialize_round_trip() {
+    let patches = [
+        Patch {
+            name: Tristate::missing(),
+            age: Tristate::missing(),
+        },
+        Patch {
+            name: Tristate::null(),
+            age: Tristate::value(7),
+        },
+        Patch {
+            name: Tristate::value("Bob".to_string()),
+            age: Tristate::null(),
+        },
+    ];
+    let expected = [
+        r#"{}"#,
+        r#"{"name":null,"age":7}"#,
+        r#"{"name":"Bob","age":null}"#,
+    ];
+    for (patch, expected) in patches.into_iter().zip(expected) {
+        let json = serde_json::to_string(&patch).unwrap();
+        assert_eq!(json, expected);
+        assert_eq!(serde_json::from_str::<Patch>(&json).unwrap(), patch);
+    }
+}
@@ -0,0 +1,47 @@
+use crate::*;
+use std::string::{String, ToString};
+use ufmt::{uDebug, uDisplay, uwrite};
+
+fn display(value: &impl uDisplay) -> String {
+    let mut s = String::new();
+    uwrite!(s, "{}", value).unwrap();
+    s
+}
+
+fn debug(value: &impl uDebug) -> String {
+    let mut s = String::new();
+    uwrite!(s, "{:?}", value).unwrap();
+    s
+}
+
+#[test]
+fn test_ufmt_display() {
+    let values: [AnyOf<u8, &str>; 4] = [
+        AnyOf::new_neither(),
+        AnyOf::new_left(42),
+        AnyOf::new_right("Hello"),
+        AnyOf::new_both(42, "a, (b"),
+    ];
+    for value in values {
+        assert_eq!(display(&value), value.to_string());
+    }
+    let either: EitherOf<u8, &str> = Right("x)");
+    assert_eq!(display(&either), either.to_string());
+    let any4: AnyOf4<u8> = AnyOf4::new4(Some(1), None, None, Some(4));
+    assert_eq!(display(&any4), "B(L(1), R(4))");
+}
+
+#[test]
+fn test_ufmt_debug() {
+    let values: [AnyOf<u8, i32>; 4] = [
+        AnyOf::new_neither(),
+        AnyOf::new_left(42),
+        AnyOf::new_right(-1),
+        AnyOf::new_both(42, -1),
+    ];
+    for value in values {
+        assert_eq!(debug(&value), std::format!("{value:?}"));
+    }
+    let both = BothOf::new(1u8, 2i32);
+    assert_eq!(debug(&both), std::format!("{both:?}"));
+}
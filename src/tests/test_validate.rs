@@ -0,0 +1,126 @@
+use crate::validate::Validator;
+use crate::*;
+
+#[derive(Copy, Clone, Debug, PartialEq, Default)]
+struct Count(u32);
+
+impl Semigroup for Count {
+    fn append(self, other: Self) -> Self {
+        Count(self.0 + other.0)
+    }
+}
+
+#[test]
+fn test_validator_clean_success() {
+    let mut validator: Validator<Count> = Validator::new();
+    let value = validator.check(Ok(21));
+    assert!(!validator.has_failed());
+    assert_eq!(validator.finish_with(|| value.unwrap() * 2), AnyOf::new_right(42));
+}
+
+#[test]
+fn test_validator_warnings() {
+    let mut validator = Validator::new();
+    let value = validator.step(AnyOf::new_both(Count(1), 21));
+    validator.warn(Count(2));
+    assert_eq!(validator.diagnostics(), Some(&Count(3)));
+    assert_eq!(validator.finish_with(|| value.unwrap() * 2), AnyOf::new_both(Count(3), 42));
+}
+
+#[test]
+fn test_validator_failure() {
+    let mut validator = Validator::new();
+    let first: Option<i32> = validator.check(Err(Count(1)));
+    let second = validator.step(AnyOf::new_both(Count(2), 2));
+    let third: Option<i32> = validator.step(AnyOf::new_neither());
+    assert_eq!((first, second, third), (None, Some(2), None));
+    assert!(validator.has_failed());
+    let validated: AnyOf<Count, i32> = validator.finish_with(|| unreachable!());
+    assert_eq!(validated, AnyOf::new_left(Count(3)));
+}
+
+#[test]
+fn test_into_result() {
+    assert_eq!(AnyOf::<Count, i32>::new_right(1).into_result(), Ok(1));
+    assert_eq!(AnyOf::new_both(Count(1), 1).into_result(), Ok(1));
+    assert_eq!(
+        AnyOf::<Count, i32>::new_left(Count(1)).into_result(),
+        Err(Some(Count(1)))
+    );
+    assert_eq!(AnyOf::<Count, i32>::new_neither().into_result(), Err(None));
+    assert_eq!(
+        AnyOf::<Count, i32>::new_neither()
+            .into_result()
+            .map_err(Option::unwrap_or_default),
+        Err(Count(0))
+    );
+
+    assert_eq!(AnyOf::<Count, i32>::new_right(1).into_strict_result(), Ok(1));
+    assert_eq!(
+        AnyOf::new_both(Count(1), 1).into_strict_result(),
+        Err(Some(Count(1)))
+    );
+    assert_eq!(
+        AnyOf::<Count, i32>::new_neither().into_strict_result(),
+        Err(None)
+    );
+}
+
+#[test]
+fn test_and_validate() {
+    let validated: AnyOf<Count, i32> = AnyOf::new_both(Count(1), 1);
+    assert_eq!(
+        validated.and_validate(|v| AnyOf::new_both(Count(2), v + 1)),
+        AnyOf::new_both(Count(3), 2)
+    );
+    assert_eq!(
+        validated.and_validate(|_| AnyOf::<Count, i32>::new_left(Count(2))),
+        AnyOf::new_left(Count(3))
+    );
+    assert_eq!(
+        validated.and_validate(|_| AnyOf::<Count, i32>::new_neither()),
+        AnyOf::new_left(Count(1))
+    );
+    let clean: AnyOf<Count, i32> = AnyOf::new_right(1);
+    assert_eq!(
+        clean.and_validate(|_| AnyOf::<Count, i32>::new_neither()),
+        AnyOf::new_neither()
+    );
+    let failed: AnyOf<Count, i32> = AnyOf::new_left(Count(1));
+    assert_eq!(
+        failed.and_validate(|v| AnyOf::new_right(v + 1)),
+        AnyOf::new_left(Count(1))
+    );
+}
+
+#[cfg(feature = "alloc")]
+#[test]
+fn test_collect() {
+    use crate::validate::collect;
+    use std::vec;
+
+    let clean: [AnyOf<&str, i32>; 2] = [AnyOf::new_right(1), AnyOf::new_right(2)];
+    assert_eq!(collect(clean), AnyOf::new_right(vec![1, 2]));
+
+    let warned: [AnyOf<&str, i32>; 3] =
+        [AnyOf::new_right(1), AnyOf::new_neither(), AnyOf::new_both("w", 2)];
+    assert_eq!(collect(warned), AnyOf::new_both(vec!["w"], vec![1, 2]));
+
+    let failed: [AnyOf<&str, i32>; 3] =
+        [AnyOf::new_left("e"), AnyOf::new_right(1), AnyOf::new_both("w", 2)];
+    assert_eq!(collect(failed), AnyOf::new_left(vec!["e", "w"]));
+}
+
+#[cfg(feature = "alloc")]
+#[test]
+fn test_validator_vec() {
+    use std::vec;
+
+    let mut validator = Validator::new();
+    let value = validator.check_one("42".parse::<i32>().map_err(|_| "not a number"));
+    validator.warn_one("deprecated");
+    assert_eq!(
+        validator.finish_with(|| value.unwrap()),
+        AnyOf::new_both(vec!["deprecated"], 42)
+    );
+}
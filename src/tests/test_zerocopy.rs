@@ -0,0 +1,22 @@
+use crate::*;
+use zerocopy::byteorder::little_endian::U32;
+use zerocopy::{FromBytes, FromZeros, IntoBytes};
+
+#[test]
+fn test_both_of_from_bytes() {
+    let bytes = [1, 0, 0, 0, 2, 0, 0, 0];
+    let both = BothOf::<U32, U32>::ref_from_bytes(&bytes).unwrap();
+    assert_eq!(both.left.get(), 1);
+    assert_eq!(both.right.get(), 2);
+
+    let both = BothOf::<u32, u32>::read_from_bytes(&bytes).unwrap();
+    assert_eq!(both, BothOf::new(u32::from_le(1), u32::from_le(2)));
+    assert!(BothOf::<u32, u32>::read_from_bytes(&bytes[..7]).is_err());
+    assert_eq!(BothOf::<u8, u16>::new_zeroed(), BothOf::new(0, 0));
+}
+
+#[test]
+fn test_both_of_into_bytes() {
+    let both = BothOf::new(U32::new(0x0403_0201), [5u8, 6]);
+    assert_eq!(both.as_bytes(), &[1, 2, 3, 4, 5, 6]);
+}
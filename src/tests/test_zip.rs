@@ -0,0 +1,73 @@
+use crate::*;
+
+#[test]
+fn test_any_of_zip() {
+    let shapes: [AnyOf<i32, char>; 4] = [
+        AnyOf::new_neither(),
+        AnyOf::new_left(1),
+        AnyOf::new_right('a'),
+        AnyOf::new_both(1, 'a'),
+    ];
+    for a in shapes {
+        for b in shapes {
+            let zipped = a.zip(b.map(|l| l * 10, |r| r.to_ascii_uppercase()));
+            assert_eq!(zipped.has_left(), a.has_left() && b.has_left());
+            assert_eq!(zipped.has_right(), a.has_right() && b.has_right());
+            if let Some((l, l2)) = zipped.left() {
+                assert_eq!(*l2, l * 10);
+            }
+            if let Some((r, r2)) = zipped.right() {
+                assert_eq!(*r2, r.to_ascii_uppercase());
+            }
+        }
+    }
+}
+
+#[test]
+fn test_any_of_zip_with() {
+    let a: AnyOf<i32, i32> = AnyOf::new_both(1, 2);
+    let b: AnyOf<i32, i32> = AnyOf::new_both(10, 20);
+    assert_eq!(
+        a.zip_with(b, |x, y| x + y, |x, y| x * y),
+        AnyOf::new_both(11, 40)
+    );
+    assert_eq!(
+        a.zip_with(AnyOf::<i32, i32>::new_neither(), |x, y| x + y, |x, y| x * y),
+        AnyOf::new_neither()
+    );
+}
+
+#[test]
+fn test_any_of_unzip() {
+    let pairs: AnyOf<(i32, char), (u8, bool)> = AnyOf::new_right((1, true));
+    assert_eq!(
+        pairs.unzip(),
+        BothOf::new(AnyOf::new_right(1), AnyOf::new_right(true))
+    );
+    let pairs: AnyOf<(i32, char), (u8, bool)> = AnyOf::new_neither();
+    assert_eq!(
+        pairs.unzip(),
+        BothOf::new(AnyOf::new_neither(), AnyOf::new_neither())
+    );
+
+    let a: AnyOf<i32, char> = AnyOf::new_both(1, 'a');
+    let b: AnyOf<u8, bool> = AnyOf::new_left(2);
+    assert_eq!(
+        a.zip(b).unzip(),
+        BothOf::new(AnyOf::new_left(1), AnyOf::new_left(2))
+    );
+}
+
+#[test]
+fn test_both_zip() {
+    let zipped = BothOf::new(1, 'a').zip(BothOf::new("one", true));
+    assert_eq!(zipped, BothOf::new((1, "one"), ('a', true)));
+    assert_eq!(
+        zipped.unzip(),
+        BothOf::new(BothOf::new(1, 'a'), BothOf::new("one", true))
+    );
+    assert_eq!(
+        BothOf::new(1, 2).zip_with(BothOf::new(3, 4), |a, b| a + b, |a, b| a * b),
+        BothOf::new(4, 8)
+    );
+}
@@ -0,0 +1,133 @@
+//! This module provides `transpose` methods flipping `Option` and `Result` wrappers outward,
+//! and [AnyOf::flatten].
+//!
+//! | Input                                  | Output                                |
+//! |----------------------------------------|---------------------------------------|
+//! | `EitherOf<Option<L>, Option<R>>`       | `Option<EitherOf<L, R>>`              |
+//! | `EitherOf<Result<L, E>, Result<R, E>>` | `Result<EitherOf<L, R>, E>`           |
+//! | `BothOf<Option<L>, Option<R>>`         | `Option<BothOf<L, R>>`                |
+//! | `BothOf<Result<L, E>, Result<R, E>>`   | `Result<BothOf<L, R>, E>`             |
+//! | `AnyOf<Option<L>, Option<R>>`          | `AnyOf<L, R>` (absent values dropped) |
+//! | `AnyOf<Result<L, E>, Result<R, E>>`    | `Result<AnyOf<L, R>, AnyOf<E, E>>`    |
+//!
+//! For `EitherOf` and `BothOf`, these methods are equivalent to a traversal
+//! ([crate::Bitraversable]) with identity functions.
+//! `AnyOf` absorbs the absent values, and collects all the errors instead of the first one.
+//!
+//! # Examples
+//!
+//! ```rust
+//! use any_of::{AnyOf, BothOf};
+//!
+//! assert_eq!(BothOf::new(Some(1), Some("one")).transpose(), Some(BothOf::new(1, "one")));
+//! assert_eq!(AnyOf::new_both(Some(1), None::<&str>).transpose(), AnyOf::new_left(1));
+//!
+//! let results: AnyOf<Result<i32, &str>, Result<u8, &str>> = AnyOf::new_both(Ok(1), Err("right"));
+//! assert_eq!(results.transpose(), Err(AnyOf::new_right("right")));
+//! ```
+
+use crate::{AnyOf, Both, BothOf, Either, EitherOf, Left, Neither, Right};
+
+impl<L, R> EitherOf<Option<L>, Option<R>> {
+    /// Transposes an `EitherOf` of options into an option of `EitherOf`.
+    pub fn transpose(self) -> Option<EitherOf<L, R>> {
+        match self {
+            Left(l) => l.map(Left),
+            Right(r) => r.map(Right),
+        }
+    }
+}
+
+impl<L, R, E> EitherOf<Result<L, E>, Result<R, E>> {
+    /// Transposes an `EitherOf` of results into a result of `EitherOf`.
+    pub fn transpose(self) -> Result<EitherOf<L, R>, E> {
+        match self {
+            Left(l) => l.map(Left),
+            Right(r) => r.map(Right),
+        }
+    }
+}
+
+impl<L, R> BothOf<Option<L>, Option<R>> {
+    /// Transposes a `BothOf` of options into an option of `BothOf`.
+    ///
+    /// Returns `None` if any of the values is `None`.
+    pub fn transpose(self) -> Option<BothOf<L, R>> {
+        Some(BothOf::new(self.left?, self.right?))
+    }
+}
+
+impl<L, R, E> BothOf<Result<L, E>, Result<R, E>> {
+    /// Transposes a `BothOf` of results into a result of `BothOf`.
+    ///
+    /// Returns the left error if any, or else the right error if any.
+    pub fn transpose(self) -> Result<BothOf<L, R>, E> {
+        Ok(BothOf::new(self.left?, self.right?))
+    }
+}
+
+impl<L, R> AnyOf<Option<L>, Option<R>> {
+    /// Transposes an `AnyOf` of options into an `AnyOf`, dropping the absent values.
+    ///
+    /// # Examples
+    /// ```rust
+    /// use any_of::AnyOf;
+    ///
+    /// let any: AnyOf<Option<i32>, Option<&str>> = AnyOf::new_both(None, Some("one"));
+    /// assert_eq!(any.transpose(), AnyOf::new_right("one"));
+    /// ```
+    pub fn transpose(self) -> AnyOf<L, R> {
+        let (left, right) = self.into_opt2();
+        AnyOf::new(left.flatten(), right.flatten())
+    }
+}
+
+impl<L, R, E> AnyOf<Result<L, E>, Result<R, E>> {
+    /// Transposes an `AnyOf` of results into a result of `AnyOf`.
+    ///
+    /// Returns `Ok` if no value is an error, or else all the errors, each on its side.
+    ///
+    /// # Examples
+    /// ```rust
+    /// use any_of::AnyOf;
+    ///
+    /// let any: AnyOf<Result<i32, &str>, Result<u8, &str>> = AnyOf::new_both(Ok(1), Ok(2));
+    /// assert_eq!(any.transpose(), Ok(AnyOf::new_both(1, 2)));
+    ///
+    /// let any: AnyOf<Result<i32, &str>, Result<u8, &str>> = AnyOf::new_both(Err("l"), Err("r"));
+    /// assert_eq!(any.transpose(), Err(AnyOf::new_both("l", "r")));
+    /// ```
+    pub fn transpose(self) -> Result<AnyOf<L, R>, AnyOf<E, E>> {
+        match self {
+            Neither => Ok(Neither),
+            Either(Left(l)) => l.map(|l| Either(Left(l))).map_err(|e| Either(Left(e))),
+            Either(Right(r)) => r.map(|r| Either(Right(r))).map_err(|e| Either(Right(e))),
+            Both(BothOf { left, right }) => match (left, right) {
+                (Ok(l), Ok(r)) => Ok(Both(BothOf::new(l, r))),
+                (left, right) => Err(AnyOf::new(left.err(), right.err())),
+            },
+        }
+    }
+}
+
+impl<L, R> AnyOf<AnyOf<L, R>, AnyOf<L, R>> {
+    /// Flattens two levels of `AnyOf` by combining the inner values, see [AnyOf::combine].
+    ///
+    /// A missing inner value is treated as `Neither`.
+    ///
+    /// # Examples
+    /// ```rust
+    /// use any_of::AnyOf;
+    ///
+    /// let nested: AnyOf<AnyOf<i32, &str>, AnyOf<i32, &str>> =
+    ///     AnyOf::new_both(AnyOf::new_left(1), AnyOf::new_right("one"));
+    /// assert_eq!(nested.flatten(), AnyOf::new_both(1, "one"));
+    ///
+    /// let nested: AnyOf<AnyOf<i32, &str>, AnyOf<i32, &str>> = AnyOf::new_right(AnyOf::new_left(2));
+    /// assert_eq!(nested.flatten(), AnyOf::new_left(2));
+    /// ```
+    pub fn flatten(self) -> AnyOf<L, R> {
+        let (left, right) = self.into_opt2();
+        left.unwrap_or(Neither).combine(right.unwrap_or(Neither))
+    }
+}
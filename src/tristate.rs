@@ -0,0 +1,205 @@
+//! This module provides [Tristate], a field which can be missing, null or hold a value,
+//! for PATCH-like payloads (`serde` feature).
+//!
+//! `Tristate<T>` wraps an `AnyOf<(), T>`:
+//!
+//! | Field          | `Tristate`             | `AnyOf<(), T>` | `Option<Option<T>>` |
+//! |----------------|------------------------|----------------|---------------------|
+//! | missing        | [Tristate::missing]    | `Neither`      | `None`              |
+//! | `null`         | [Tristate::null]       | `Left(())`     | `Some(None)`        |
+//! | value          | [Tristate::value]      | `Right(value)` | `Some(Some(value))` |
+//!
+//! To tell the three states apart, a field must be declared with
+//! `#[serde(default, skip_serializing_if = "Tristate::is_missing")]`:
+//! a missing field deserializes to the default (missing) value, and is skipped on serialization.
+//! With the `schemars` feature, the JSON schema of a `Tristate<T>` is the one of a nullable `T`.
+//!
+//! # Examples
+//!
+//! ```rust
+//! use any_of::tristate::Tristate;
+//! use serde::{Deserialize, Serialize};
+//!
+//! #[derive(Serialize, Deserialize)]
+//! struct UserPatch {
+//!     #[serde(default, skip_serializing_if = "Tristate::is_missing")]
+//!     name: Tristate<String>,
+//!     #[serde(default, skip_serializing_if = "Tristate::is_missing")]
+//!     email: Tristate<String>,
+//! }
+//!
+//! let patch: UserPatch = serde_json::from_str(r#"{"email": null}"#).unwrap();
+//! assert!(patch.name.is_missing());
+//! assert!(patch.email.is_null());
+//! assert_eq!(serde_json::to_string(&patch).unwrap(), r#"{"email":null}"#);
+//!
+//! let mut email = Some("old@example.com".to_string());
+//! patch.email.apply_to(&mut email);
+//! assert_eq!(email, None);
+//! ```
+
+use crate::{AnyOf, Both, BothOf, Either, Left, LeftOrRight, Neither, Right};
+use serde::{Deserialize, Deserializer, Serialize, Serializer};
+
+/// A field which can be missing, null or hold a value.
+///
+/// See the [module documentation](self).
+#[derive(Copy, Clone, Eq, PartialEq, Ord, PartialOrd, Debug, Hash)]
+pub struct Tristate<T>(AnyOf<(), T>);
+
+impl<T> Tristate<T> {
+    /// Creates a missing field.
+    pub fn missing() -> Self {
+        Tristate(Neither)
+    }
+
+    /// Creates a null field.
+    pub fn null() -> Self {
+        Tristate(Either(Left(())))
+    }
+
+    /// Creates a field holding `value`.
+    pub fn value(value: T) -> Self {
+        Tristate(Either(Right(value)))
+    }
+
+    /// Returns `true` if the field is missing.
+    pub fn is_missing(&self) -> bool {
+        self.0.is_neither()
+    }
+
+    /// Returns `true` if the field is null.
+    pub fn is_null(&self) -> bool {
+        self.0.is_left()
+    }
+
+    /// Returns `true` if the field holds a value.
+    pub fn is_value(&self) -> bool {
+        self.0.has_right()
+    }
+
+    /// Returns a reference to the value, if any.
+    pub fn as_value(&self) -> Option<&T> {
+        self.0.right()
+    }
+
+    /// Returns the field as an `AnyOf<(), T>`: `Neither`, `Left(())` or `Right(value)`.
+    pub fn as_any_of(&self) -> &AnyOf<(), T> {
+        &self.0
+    }
+
+    /// Converts into an `AnyOf<(), T>`: `Neither`, `Left(())` or `Right(value)`.
+    pub fn into_any_of(self) -> AnyOf<(), T> {
+        self.0
+    }
+
+    /// Converts into an `Option<Option<T>>`: `None` if missing, `Some(None)` if null.
+    pub fn into_option(self) -> Option<Option<T>> {
+        match self.0 {
+            Neither => None,
+            Either(Left(())) => Some(None),
+            Either(Right(value)) | Both(BothOf { right: value, .. }) => Some(Some(value)),
+        }
+    }
+
+    /// Returns `update` unless it is missing, or else `self`.
+    ///
+    /// # Examples
+    /// ```rust
+    /// use any_of::tristate::Tristate;
+    ///
+    /// assert_eq!(Tristate::value(1).patch(Tristate::missing()), Tristate::value(1));
+    /// assert_eq!(Tristate::value(1).patch(Tristate::null()), Tristate::null());
+    /// ```
+    pub fn patch(self, update: Self) -> Self {
+        if update.is_missing() { self } else { update }
+    }
+
+    /// Applies the field to `target`: a missing field leaves it untouched,
+    /// a null field clears it, and a value replaces it.
+    pub fn apply_to(self, target: &mut Option<T>) {
+        if let Some(value) = self.into_option() {
+            *target = value;
+        }
+    }
+}
+
+impl<T> Default for Tristate<T> {
+    /// Returns a missing field.
+    fn default() -> Self {
+        Tristate::missing()
+    }
+}
+
+impl<T> From<Option<Option<T>>> for Tristate<T> {
+    fn from(option: Option<Option<T>>) -> Self {
+        match option {
+            None => Tristate::missing(),
+            Some(None) => Tristate::null(),
+            Some(Some(value)) => Tristate::value(value),
+        }
+    }
+}
+
+impl<T> From<Tristate<T>> for Option<Option<T>> {
+    fn from(tristate: Tristate<T>) -> Self {
+        tristate.into_option()
+    }
+}
+
+impl<T> From<AnyOf<(), T>> for Tristate<T> {
+    /// Converts `Neither`, `Left(())` and `Right(value)`; `Both((), value)` holds the value.
+    fn from(any: AnyOf<(), T>) -> Self {
+        match any {
+            Both(both) => Tristate::value(both.right),
+            any => Tristate(any),
+        }
+    }
+}
+
+impl<T> From<Tristate<T>> for AnyOf<(), T> {
+    fn from(tristate: Tristate<T>) -> Self {
+        tristate.into_any_of()
+    }
+}
+
+impl<T: Serialize> Serialize for Tristate<T> {
+    /// Serializes a value as `Some(value)`, and a null or missing field as `None`.
+    ///
+    /// A missing field is expected to be skipped with `skip_serializing_if = "Tristate::is_missing"`.
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        match self.as_value() {
+            Some(value) => serializer.serialize_some(value),
+            None => serializer.serialize_none(),
+        }
+    }
+}
+
+impl<'de, T: Deserialize<'de>> Deserialize<'de> for Tristate<T> {
+    /// Deserializes a null field or a value.
+    ///
+    /// A missing field is expected to be filled with `#[serde(default)]`.
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        Option::<T>::deserialize(deserializer).map(|option| Some(option).into())
+    }
+}
+
+#[cfg(feature = "schemars")]
+impl<T: schemars::JsonSchema> schemars::JsonSchema for Tristate<T> {
+    fn inline_schema() -> bool {
+        true
+    }
+
+    fn schema_name() -> alloc::borrow::Cow<'static, str> {
+        Option::<T>::schema_name()
+    }
+
+    fn schema_id() -> alloc::borrow::Cow<'static, str> {
+        Option::<T>::schema_id()
+    }
+
+    /// The schema of a nullable `T`, like `Option<T>`.
+    fn json_schema(generator: &mut schemars::SchemaGenerator) -> schemars::Schema {
+        Option::<T>::json_schema(generator)
+    }
+}
@@ -0,0 +1,276 @@
+//! This module provides a "These"-style validation mode built on top of [AnyOf].
+//!
+//! A validated value is an `AnyOf<E, T>` where `E` accumulates the diagnostics
+//! and `T` is the produced value:
+//!
+//! - `Right(value)`: clean success,
+//! - `Both(diagnostics, value)`: success with warnings,
+//! - `Left(diagnostics)`: failure,
+//! - `Neither`: nothing was validated.
+//!
+//! The diagnostics are merged with the [Semigroup] trait, which is implemented for
+//! `Vec` and `String` with the `alloc` feature.
+//!
+//! # Examples
+//!
+//! ```rust
+//! use any_of::validate::Validator;
+//! use any_of::AnyOf;
+//!
+//! #[derive(Debug, PartialEq)]
+//! struct Count(u32);
+//!
+//! impl any_of::Semigroup for Count {
+//!     fn append(self, other: Self) -> Self {
+//!         Count(self.0 + other.0)
+//!     }
+//! }
+//!
+//! let mut validator = Validator::new();
+//! let port = validator.check("8080".parse::<u16>().map_err(|_| Count(1)));
+//! validator.warn(Count(1));
+//! let validated: AnyOf<Count, u16> = validator.finish_with(|| port.unwrap());
+//!
+//! assert_eq!(validated, AnyOf::new_both(Count(1), 8080));
+//! assert_eq!(validated.into_result(), Ok(8080));
+//! ```
+
+use crate::{AnyOf, Both, BothOf, Either, Left, Neither, Right, Semigroup};
+
+/// The type of a validated value: diagnostics on the left, value on the right.
+pub type Validated<E, T> = AnyOf<E, T>;
+
+/// Accumulates the diagnostics of several validation steps.
+///
+/// Each step is either a failure (the diagnostic is recorded and the validation fails),
+/// a warning (the diagnostic is recorded but the validation goes on) or a success.
+///
+/// The final [Validated] value is built with [Validator::finish] or [Validator::finish_with].
+#[derive(Clone, Eq, PartialEq, Debug, Hash)]
+pub struct Validator<E> {
+    diagnostics: Option<E>,
+    failed: bool,
+}
+
+impl<E> Default for Validator<E> {
+    fn default() -> Self {
+        Self {
+            diagnostics: None,
+            failed: false,
+        }
+    }
+}
+
+impl<E: Semigroup> Validator<E> {
+    /// Creates a validator without diagnostics.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// True if a step has failed.
+    pub fn has_failed(&self) -> bool {
+        self.failed
+    }
+
+    /// Returns the diagnostics accumulated so far.
+    pub fn diagnostics(&self) -> Option<&E> {
+        self.diagnostics.as_ref()
+    }
+
+    /// Records a diagnostic without failing the validation.
+    pub fn warn(&mut self, diagnostic: E) {
+        self.diagnostics = self.diagnostics.take().append(Some(diagnostic));
+    }
+
+    /// Records a diagnostic and fails the validation.
+    pub fn fail(&mut self, diagnostic: E) {
+        self.warn(diagnostic);
+        self.failed = true;
+    }
+
+    /// Runs a fallible step.
+    ///
+    /// Returns the value of `Ok`, or records the diagnostic of `Err` as a failure and returns `None`.
+    pub fn check<T>(&mut self, result: Result<T, E>) -> Option<T> {
+        match result {
+            Ok(value) => Some(value),
+            Err(diagnostic) => {
+                self.fail(diagnostic);
+                None
+            }
+        }
+    }
+
+    /// Runs a validated step.
+    ///
+    /// - `Right(value)` returns the value,
+    /// - `Both(diagnostic, value)` records a warning and returns the value,
+    /// - `Left(diagnostic)` records a failure and returns `None`,
+    /// - `Neither` returns `None` without recording anything.
+    pub fn step<T>(&mut self, validated: Validated<E, T>) -> Option<T> {
+        match validated {
+            Neither => None,
+            Either(Left(diagnostic)) => {
+                self.fail(diagnostic);
+                None
+            }
+            Either(Right(value)) => Some(value),
+            Both(BothOf { left, right }) => {
+                self.warn(left);
+                Some(right)
+            }
+        }
+    }
+
+    /// Builds the final [Validated] value.
+    ///
+    /// - `Left(diagnostics)` if a step has failed,
+    /// - `Both(diagnostics, value)` if warnings were recorded,
+    /// - `Right(value)` otherwise.
+    pub fn finish<T>(self, value: T) -> Validated<E, T> {
+        self.finish_with(|| value)
+    }
+
+    /// Builds the final [Validated] value, computing the value only if no step has failed.
+    ///
+    /// See [Validator::finish].
+    pub fn finish_with<T>(self, f: impl FnOnce() -> T) -> Validated<E, T> {
+        if self.failed {
+            AnyOf::new(self.diagnostics, None)
+        } else {
+            AnyOf::new(self.diagnostics, Some(f()))
+        }
+    }
+}
+
+impl<E, T> AnyOf<E, T> {
+    /// Converts a [Validated] value into a `Result`, dropping the warnings of `Both`.
+    ///
+    /// This is [AnyOf::ok_or_left]: `Left(e)` is converted to `Err(Some(e))` and `Neither`
+    /// to `Err(None)`. When `E: Default`, `map_err(Option::unwrap_or_default)` gives a
+    /// `Result<T, E>`.
+    ///
+    /// # Examples
+    /// ```rust
+    /// use any_of::AnyOf;
+    ///
+    /// fn parse(validated: AnyOf<&'static str, i32>) -> Result<i32, Option<&'static str>> {
+    ///     let value = validated.into_result()?;
+    ///     Ok(value * 2)
+    /// }
+    ///
+    /// assert_eq!(parse(AnyOf::new_both("warning", 21)), Ok(42));
+    /// assert_eq!(parse(AnyOf::new_left("error")), Err(Some("error")));
+    /// assert_eq!(parse(AnyOf::new_neither()), Err(None));
+    /// ```
+    pub fn into_result(self) -> Result<T, Option<E>> {
+        self.ok_or_left()
+    }
+
+    /// Converts a [Validated] value into a `Result`, treating the warnings of `Both` as errors.
+    ///
+    /// `Left(e)` and `Both(e, _)` are converted to `Err(Some(e))`, and `Neither` to `Err(None)`.
+    pub fn into_strict_result(self) -> Result<T, Option<E>> {
+        match self {
+            Neither => Err(None),
+            Either(Left(e)) | Both(BothOf { left: e, .. }) => Err(Some(e)),
+            Either(Right(t)) => Ok(t),
+        }
+    }
+
+    /// Chains a validation step, accumulating the diagnostics.
+    ///
+    /// - `Left(e)` stays `Left(e)` and `f` is not called,
+    /// - `Neither` stays `Neither` and `f` is not called,
+    /// - otherwise, the result has the diagnostics of `Both` followed by the ones returned by
+    ///   `f`, and the value returned by `f`: if `f` returns `Neither` (nothing was validated),
+    ///   `Both(warnings, _)` gives `Left(warnings)`, so that no warning is lost.
+    ///
+    /// # Examples
+    /// ```rust
+    /// use any_of::AnyOf;
+    ///
+    /// let validated: AnyOf<Option<()>, i32> = AnyOf::new_both(Some(()), 21);
+    /// let doubled = validated.and_validate(|v| AnyOf::new_right(v * 2));
+    /// assert_eq!(doubled, AnyOf::new_both(Some(()), 42));
+    ///
+    /// let nothing = validated.and_validate(|_| AnyOf::<Option<()>, i32>::new_neither());
+    /// assert_eq!(nothing, AnyOf::new_left(Some(())));
+    /// ```
+    pub fn and_validate<U>(self, f: impl FnOnce(T) -> AnyOf<E, U>) -> AnyOf<E, U>
+    where
+        E: Semigroup,
+    {
+        match self {
+            Neither => Neither,
+            Either(Left(e)) => Either(Left(e)),
+            Either(Right(t)) => f(t),
+            Both(BothOf { left, right }) => match f(right) {
+                Neither => Either(Left(left)),
+                Either(Left(e)) => Either(Left(left.append(e))),
+                Either(Right(u)) => Both(BothOf::new(left, u)),
+                Both(BothOf { left: e, right: u }) => Both(BothOf::new(left.append(e), u)),
+            },
+        }
+    }
+}
+
+/// Collects validated values into a single [Validated] value.
+///
+/// All the diagnostics are collected, in order, and all the values are collected, in order.
+/// `Neither` items are ignored.
+///
+/// - `Left(diagnostics)` if any item is `Left`,
+/// - `Both(diagnostics, values)` if any item is `Both`,
+/// - `Right(values)` otherwise.
+///
+/// # Examples
+/// ```rust
+/// use any_of::validate::collect;
+/// use any_of::AnyOf;
+///
+/// let items: [AnyOf<&str, i32>; 2] = [AnyOf::new_right(1), AnyOf::new_both("warning", 2)];
+/// assert_eq!(collect(items), AnyOf::new_both(vec!["warning"], vec![1, 2]));
+/// ```
+#[cfg(feature = "alloc")]
+pub fn collect<E, T>(
+    items: impl IntoIterator<Item = Validated<E, T>>,
+) -> Validated<alloc::vec::Vec<E>, alloc::vec::Vec<T>> {
+    let mut diagnostics = alloc::vec::Vec::new();
+    let mut values = alloc::vec::Vec::new();
+    let mut failed = false;
+    for item in items {
+        match item {
+            Neither => {}
+            Either(Left(e)) => {
+                failed = true;
+                diagnostics.push(e);
+            }
+            Either(Right(t)) => values.push(t),
+            Both(BothOf { left, right }) => {
+                diagnostics.push(left);
+                values.push(right);
+            }
+        }
+    }
+    let diagnostics = (!diagnostics.is_empty()).then_some(diagnostics);
+    let values = (!failed).then_some(values);
+    AnyOf::new(diagnostics, values)
+}
+
+#[cfg(feature = "alloc")]
+impl<E> Validator<alloc::vec::Vec<E>> {
+    /// Records a single diagnostic as a warning.
+    ///
+    /// See [Validator::warn].
+    pub fn warn_one(&mut self, diagnostic: E) {
+        self.warn(alloc::vec![diagnostic]);
+    }
+
+    /// Runs a fallible step, collecting its single diagnostic on failure.
+    ///
+    /// See [Validator::check].
+    pub fn check_one<T>(&mut self, result: Result<T, E>) -> Option<T> {
+        self.check(result.map_err(|e| alloc::vec![e]))
+    }
+}
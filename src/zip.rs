@@ -0,0 +1,127 @@
+//! This module provides `zip`, `zip_with` and `unzip` for [AnyOf] and [BothOf].
+//!
+//! - [AnyOf::zip] pairs two `AnyOf` side by side: a side is present only when it is
+//!   present in both operands,
+//! - [AnyOf::unzip] splits an `AnyOf` of pairs into a [BothOf] of `AnyOf` with the same shape.
+//!
+//! # Examples
+//!
+//! ```rust
+//! use any_of::{AnyOf, BothOf};
+//!
+//! let request: AnyOf<u32, &str> = AnyOf::new_both(1, "GET");
+//! let response: AnyOf<u32, &str> = AnyOf::new_left(200);
+//! let zipped = request.zip(response);
+//! assert_eq!(zipped, AnyOf::new_left((1, 200)));
+//! assert_eq!(zipped.unzip(), BothOf::new(AnyOf::new_left(1), AnyOf::new_left(200)));
+//! ```
+
+use crate::{AnyOf, BothOf};
+
+impl<L, R> AnyOf<L, R> {
+    /// Pairs the values of `self` and `other` side by side.
+    ///
+    /// A side of the result is present only when it is present in both operands.
+    ///
+    /// # Examples
+    /// ```rust
+    /// use any_of::AnyOf;
+    ///
+    /// let a: AnyOf<i32, char> = AnyOf::new_both(1, 'a');
+    /// let b: AnyOf<i32, char> = AnyOf::new_right('b');
+    /// assert_eq!(a.zip(b), AnyOf::new_right(('a', 'b')));
+    /// ```
+    pub fn zip<L2, R2>(self, other: AnyOf<L2, R2>) -> AnyOf<(L, L2), (R, R2)> {
+        self.zip_with(other, |l, l2| (l, l2), |r, r2| (r, r2))
+    }
+
+    /// Combines the values of `self` and `other` side by side with `fl` and `fr`.
+    ///
+    /// A side of the result is present only when it is present in both operands.
+    /// See [AnyOf::zip].
+    ///
+    /// # Examples
+    /// ```rust
+    /// use any_of::AnyOf;
+    ///
+    /// let a: AnyOf<i32, &str> = AnyOf::new_both(1, "a");
+    /// let b: AnyOf<i32, &str> = AnyOf::new_both(2, "b");
+    /// assert_eq!(a.zip_with(b, |x, y| x + y, |x, y| [x, y].concat()), AnyOf::new_both(3, "ab".to_string()));
+    /// ```
+    pub fn zip_with<L2, R2, L3, R3, FL, FR>(
+        self,
+        other: AnyOf<L2, R2>,
+        fl: FL,
+        fr: FR,
+    ) -> AnyOf<L3, R3>
+    where
+        FL: FnOnce(L, L2) -> L3,
+        FR: FnOnce(R, R2) -> R3,
+    {
+        let (left, right) = self.into_opt2();
+        let (left2, right2) = other.into_opt2();
+        AnyOf::new(
+            left.zip(left2).map(|(l, l2)| fl(l, l2)),
+            right.zip(right2).map(|(r, r2)| fr(r, r2)),
+        )
+    }
+}
+
+impl<A, B, C, D> AnyOf<(A, B), (C, D)> {
+    /// Splits an `AnyOf` of pairs into a [BothOf] of two `AnyOf` with the same shape:
+    /// the first elements on the left, the second elements on the right.
+    ///
+    /// # Examples
+    /// ```rust
+    /// use any_of::{AnyOf, BothOf};
+    ///
+    /// let pairs: AnyOf<(i32, char), (&str, bool)> = AnyOf::new_both((1, 'a'), ("b", true));
+    /// assert_eq!(
+    ///     pairs.unzip(),
+    ///     BothOf::new(AnyOf::new_both(1, "b"), AnyOf::new_both('a', true))
+    /// );
+    /// ```
+    pub fn unzip(self) -> BothOf<AnyOf<A, C>, AnyOf<B, D>> {
+        let (left, right) = self.into_opt2();
+        let (a, b) = left.unzip();
+        let (c, d) = right.unzip();
+        BothOf::new(AnyOf::new(a, c), AnyOf::new(b, d))
+    }
+}
+
+impl<L, R> BothOf<L, R> {
+    /// Pairs the values of `self` and `other` side by side.
+    ///
+    /// # Examples
+    /// ```rust
+    /// use any_of::BothOf;
+    ///
+    /// assert_eq!(BothOf::new(1, 'a').zip(BothOf::new(2, 'b')), BothOf::new((1, 2), ('a', 'b')));
+    /// ```
+    pub fn zip<L2, R2>(self, other: BothOf<L2, R2>) -> BothOf<(L, L2), (R, R2)> {
+        self.zip_with(other, |l, l2| (l, l2), |r, r2| (r, r2))
+    }
+
+    /// Combines the values of `self` and `other` side by side with `fl` and `fr`.
+    pub fn zip_with<L2, R2, L3, R3, FL, FR>(
+        self,
+        other: BothOf<L2, R2>,
+        fl: FL,
+        fr: FR,
+    ) -> BothOf<L3, R3>
+    where
+        FL: FnOnce(L, L2) -> L3,
+        FR: FnOnce(R, R2) -> R3,
+    {
+        BothOf::new(fl(self.left, other.left), fr(self.right, other.right))
+    }
+}
+
+impl<A, B, C, D> BothOf<(A, B), (C, D)> {
+    /// Splits a `BothOf` of pairs into a `BothOf` of two `BothOf`:
+    /// the first elements on the left, the second elements on the right.
+    pub fn unzip(self) -> BothOf<BothOf<A, C>, BothOf<B, D>> {
+        let ((a, b), (c, d)) = (self.left, self.right);
+        BothOf::new(BothOf::new(a, c), BothOf::new(b, d))
+    }
+}